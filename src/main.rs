@@ -1,55 +1,256 @@
-mod day1;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day2;
-mod day20;
-mod day21;
-mod day22;
-mod day23;
-mod day24;
-mod day25;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::process;
+
+use utilities::{
+    Args, BenchOptions, Command, Days, Fetched, Fetcher, InputFile, Journal, Outcome, OutputFormat,
+    Part, PartResult, Registry, SiteError, Submitter,
+};
 
 fn main() {
-    println!("Advent of code 2023");
-
-    day1::run();
-    day2::run();
-    day3::run();
-    day4::run();
-    day5::run();
-    day6::run();
-    day7::run();
-    day8::run();
-    day9::run();
-    day10::run();
-    day11::run();
-    day12::run();
-    day13::run();
-    day14::run();
-    day15::run();
-    day16::run();
-    day17::run();
-    day18::run();
-    day19::run();
-    day20::run();
-    day21::run();
-    day22::run();
-    day23::run();
-    day24::run();
-    day25::run();
+    let Args { year, command } = match utilities::parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{e}\n\n{}", utilities::USAGE);
+            process::exit(2);
+        }
+    };
+
+    match command {
+        Command::Run(options) => {
+            let registry = registry(year);
+
+            // All means every day that has a solution, earlier years may have only a few.
+            let days = match &options.days {
+                Days::All => registry.iter().map(|solution| solution.day()).collect(),
+                Days::List(days) => days.clone(),
+            };
+
+            let solutions = match registry.select(&days) {
+                Ok(solutions) => solutions,
+                Err(missing) => {
+                    eprintln!("No solution registered for days {missing:?}");
+                    process::exit(2);
+                }
+            };
+
+            let header = options.format.header(year);
+
+            if !header.is_empty() {
+                println!("{header}");
+            }
+
+            // A day that can't be loaded is reported and skipped, the other days still run.
+            let summary = utilities::run_puzzles(&solutions, &options);
+
+            let total = format!(
+                "Total: {:?} wall time, {:?} in parts",
+                summary.wall_time,
+                summary.part_time()
+            );
+
+            // Keep stdout to just the records for the machine readable formats.
+            if options.format == OutputFormat::Text {
+                println!("{total}");
+            } else {
+                eprintln!("{total}");
+            }
+
+            if let Some(bench) = &options.bench {
+                save_and_compare_bench(bench, &summary.results);
+            }
+
+            if summary.failed() {
+                process::exit(1);
+            }
+        }
+        Command::Fetch(days) => fetch_inputs(year, &days.to_vec()),
+        Command::Submit { day, part, answer } => submit_answer(year, day, part, answer),
+        Command::ImportNotes(notes) => {
+            import_notes(year, &notes.unwrap_or_else(|| utilities::notes_path(year)))
+        }
+        Command::Report(bench) => print_report(year, bench.as_deref()),
+        Command::NewDay(day) => match utilities::new_day(Path::new("."), year, day) {
+            Ok(changed) => {
+                for path in changed {
+                    println!("Wrote {}", path.display());
+                }
+            }
+            Err(e) => {
+                eprintln!("Failed to add day {day}: {e}");
+                process::exit(1);
+            }
+        },
+        Command::List => {
+            for solution in registry(year).iter() {
+                println!("Day {:>2}: {}", solution.day(), solution.title());
+            }
+        }
+        Command::Help => println!("{}", utilities::USAGE),
+    }
+}
+
+fn save_and_compare_bench(bench: &BenchOptions, results: &[PartResult]) {
+    if let Some(path) = &bench.compare {
+        if let Err(e) = utilities::compare_bench(path, results) {
+            eprintln!("Failed to compare with {}: {e}", path.display());
+        }
+    }
+
+    if let Some(path) = &bench.save {
+        if let Err(e) = utilities::save_bench(path, results) {
+            eprintln!("Failed to save to {}: {e}", path.display());
+        }
+    }
+}
+
+fn session() -> String {
+    match utilities::session_from_env() {
+        Ok(session) => session,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(2);
+        }
+    }
+}
+
+fn fetch_inputs(year: usize, days: &[usize]) {
+    let base_url = utilities::base_url_from_env();
+
+    let mut fetcher = Fetcher::new(utilities::client_for(&base_url), session());
+    fetcher.base_url = base_url;
+    fetcher.year = year;
+    let mut failed = false;
+
+    for &day in days {
+        match fetcher.fetch(day) {
+            Ok(Fetched::Downloaded(path)) => println!("Day {day}: saved {}", path.display()),
+            Ok(Fetched::Cached(path)) => println!("Day {day}: already have {}", path.display()),
+            // Later days can still be fetched when one isn't out yet.
+            Err(e @ SiteError::Locked(_)) => {
+                eprintln!("{e}");
+                failed = true;
+            }
+            Err(e) => {
+                eprintln!("Day {day}: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn submit_answer(year: usize, day: usize, part: Part, answer: Option<String>) {
+    let session = session();
+
+    let answer = answer.unwrap_or_else(|| solve_input(year, day, part));
+
+    let base_url = utilities::base_url_from_env();
+
+    let mut submitter = Submitter::new(utilities::client_for(&base_url), session);
+    submitter.base_url = base_url;
+    submitter.year = year;
+
+    println!("Day {day} part {part}: submitting {answer}");
+
+    match submitter.submit(day, part, &answer) {
+        Ok(outcome) => {
+            println!("{outcome}");
+
+            if !matches!(outcome, Outcome::Correct | Outcome::AlreadySolved) {
+                process::exit(1);
+            }
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    }
+}
+
+// Runs the part on the day's input to get the answer to submit.
+fn solve_input(year: usize, day: usize, part: Part) -> String {
+    let registry = registry(year);
+
+    let Some(solution) = registry.get(day) else {
+        eprintln!("No solution registered for day {day}");
+        process::exit(2);
+    };
+
+    let path = InputFile::Input.path(year, day).unwrap();
+
+    let answer = fs::read_to_string(&path)
+        .map_err(|e| utilities::PuzzleError::io(&path, e))
+        .and_then(|data| match part {
+            Part::One => solution.part_1(&data),
+            Part::Two => solution.part_2(&data),
+        });
+
+    answer.unwrap_or_else(|e| {
+        eprintln!("{}", e.in_day(day));
+        process::exit(1);
+    })
+}
+
+fn import_notes(year: usize, notes: &Path) {
+    let journal_path = utilities::journal_path(year);
+
+    // The journal is the log from now on, so never write over it.
+    if journal_path.exists() {
+        eprintln!("{} already exists", journal_path.display());
+        process::exit(1);
+    }
+
+    let journal = fs::read_to_string(notes)
+        .map_err(|e| utilities::PuzzleError::io(notes, e))
+        .and_then(|data| Journal::import_notes(&data).map_err(|e| e.in_file(notes)));
+
+    let journal = journal.unwrap_or_else(|e| {
+        eprintln!("{e}");
+        process::exit(1);
+    });
+
+    if let Err(e) = fs::write(&journal_path, journal.to_toml()) {
+        eprintln!("Failed to write {}: {e}", journal_path.display());
+        process::exit(1);
+    }
+
+    println!(
+        "Imported {} solves into {}",
+        journal.solves.len(),
+        journal_path.display()
+    );
+}
+
+fn print_report(year: usize, bench: Option<&Path>) {
+    let journal = Journal::load(&utilities::journal_path(year)).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        process::exit(1);
+    });
+
+    let current = match bench {
+        Some(path) => utilities::load_bench(path).unwrap_or_else(|e| {
+            eprintln!("Failed to read {}: {e}", path.display());
+            process::exit(1);
+        }),
+        None => HashMap::new(),
+    };
+
+    println!("{}", utilities::journal_report(&journal, year, &current));
+}
+
+// new-day adds a new year here, along with its crate in Cargo.toml.
+fn registry(year: usize) -> Registry {
+    match year {
+        2015 => year2015::registry(),
+        2023 => year2023::registry(),
+        _ => {
+            eprintln!("No solutions for {year}");
+            process::exit(2);
+        }
+    }
 }
@@ -0,0 +1,146 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::{Part, PuzzleError, PuzzleResult};
+
+// The accepted answers for one puzzle file, stored next to it with an .answers extension
+// (input.txt -> input.answers). Each line is "part_1 = <answer>" or "part_2 = <answer>", and
+// lines starting with '#' are comments. A part without a line, or with nothing after the '=', is
+// unknown.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    part_1: Option<String>,
+    part_2: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    Unknown,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Pass => "PASS",
+            Self::Fail => "FAIL",
+            Self::Unknown => "UNKNOWN",
+        };
+
+        write!(f, "{s}")
+    }
+}
+
+impl Answers {
+    pub fn parse(data: &str) -> PuzzleResult<Self> {
+        let mut answers = Self::default();
+
+        for (i, line) in data.lines().enumerate() {
+            let trimmed = line.trim();
+
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            let Some((key, value)) = trimmed.split_once('=') else {
+                return Err(PuzzleError::parse("Expected part_N = <answer>").at_line(i + 1, line));
+            };
+
+            let value = Some(value.trim())
+                .filter(|value| !value.is_empty())
+                .map(str::to_string);
+
+            match key.trim() {
+                "part_1" => answers.part_1 = value,
+                "part_2" => answers.part_2 = value,
+                key => {
+                    return Err(PuzzleError::parse(format!("Illegal answers key [{key}]"))
+                        .at_line(i + 1, line))
+                }
+            }
+        }
+
+        Ok(answers)
+    }
+
+    // Loads the answers for the given puzzle file. A missing answers file means nothing is known.
+    pub fn load(puzzle_path: &Path) -> PuzzleResult<Self> {
+        let path = answers_path(puzzle_path);
+
+        match fs::read_to_string(&path) {
+            Ok(data) => Self::parse(&data).map_err(|e| e.in_file(&path)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(PuzzleError::io(&path, e)),
+        }
+    }
+
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part_1.as_deref(),
+            Part::Two => self.part_2.as_deref(),
+        }
+    }
+
+    pub fn check(&self, part: Part, answer: &str) -> Verdict {
+        match self.get(part) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(_) => Verdict::Fail,
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+pub fn answers_path(puzzle_path: &Path) -> PathBuf {
+    puzzle_path.with_extension("answers")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_answers() {
+        let answers =
+            Answers::parse("# From the puzzle page\n\npart_1 = 142\n  part_2=a = b  \n").unwrap();
+
+        assert_eq!(answers.get(Part::One), Some("142"));
+        assert_eq!(answers.get(Part::Two), Some("a = b"));
+        assert_eq!(Answers::parse("").unwrap(), Answers::default());
+
+        // A part that is only in a comment, or has no value yet, is unknown.
+        let answers = Answers::parse("# part_1 = 5\npart_2 =\n").unwrap();
+        assert_eq!(answers, Answers::default());
+    }
+
+    #[test]
+    fn rejects_bad_lines() {
+        let e = Answers::parse("part_1 = 1\npart_3 = 2").unwrap_err();
+        assert_eq!(e.line(), Some(2));
+        assert_eq!(
+            e.to_string(),
+            "line 2: Illegal answers key [part_3] in [part_3 = 2]"
+        );
+
+        let e = Answers::parse("\n142").unwrap_err();
+        assert_eq!(e.line(), Some(2));
+        assert_eq!(e.text(), Some("142"));
+    }
+
+    #[test]
+    fn checks_answers() {
+        let answers = Answers::parse("part_1 = 142\npart_2 =").unwrap();
+
+        assert_eq!(answers.check(Part::One, "142"), Verdict::Pass);
+        assert_eq!(answers.check(Part::One, "143"), Verdict::Fail);
+        assert_eq!(answers.check(Part::Two, ""), Verdict::Unknown);
+        assert_eq!(answers.check(Part::Two, "0"), Verdict::Unknown);
+
+        assert_eq!(
+            answers_path(Path::new("test_files/day1/input.txt")),
+            PathBuf::from("test_files/day1/input.answers")
+        );
+    }
+}
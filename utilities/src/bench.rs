@@ -0,0 +1,388 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::memo::take_thread_stats;
+use crate::{MemoStats, PartResult};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchOptions {
+    pub warmup: usize,
+    pub iterations: usize,
+    pub save: Option<PathBuf>,
+    pub compare: Option<PathBuf>,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            iterations: 10,
+            save: None,
+            compare: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BenchStats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+    pub iterations: usize,
+    // How the memos did in the last run, for the days that use one.
+    pub memo: Option<MemoStats>,
+}
+
+impl BenchStats {
+    // The statistics of the timed runs, or None if there weren't any.
+    fn from_samples(mut samples: Vec<Duration>) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        samples.sort();
+
+        let n = samples.len();
+
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+
+        let secs = samples
+            .iter()
+            .map(|d| d.as_secs_f64())
+            .collect::<Vec<f64>>();
+
+        let mean = secs.iter().sum::<f64>() / n as f64;
+
+        // Sample standard deviation, since the runs are a sample of all possible runs.
+        let variance = if n > 1 {
+            secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0.0
+        };
+
+        Some(Self {
+            min: samples[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+            iterations: n,
+            memo: None,
+        })
+    }
+}
+
+impl fmt::Display for BenchStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "min {:?}, median {:?}, mean {:?}, stddev {:?}, {} runs",
+            self.min, self.median, self.mean, self.stddev, self.iterations
+        )?;
+
+        if let Some(memo) = self.memo {
+            write!(f, ", memo {memo}")?;
+        }
+
+        Ok(())
+    }
+}
+
+// Runs f a few times without measuring to warm up caches, then times each of the measured runs.
+// There is always at least one measured run, which gives the result.
+pub fn benchmark<F, T>(f: F, data: &str, options: &BenchOptions) -> (T, BenchStats)
+where
+    F: Fn(&str) -> T,
+{
+    for _ in 0..options.warmup {
+        f(data);
+    }
+
+    take_thread_stats();
+
+    let now = Instant::now();
+    let mut result = f(data);
+    let mut samples = vec![now.elapsed()];
+
+    for _ in 1..options.iterations {
+        take_thread_stats();
+
+        let now = Instant::now();
+        result = f(data);
+
+        samples.push(now.elapsed());
+    }
+
+    let mut stats = BenchStats::from_samples(samples).expect("There is always a measured run");
+    stats.memo = Some(take_thread_stats()).filter(|memo| memo.lookups() > 0);
+
+    (result, stats)
+}
+
+// Saves the benchmark results with one line per day and part, so that two saved runs can be
+// diffed or compared with compare_bench. Times are in nanoseconds.
+pub fn save_bench(path: &Path, results: &[PartResult]) -> io::Result<()> {
+    let mut contents = String::from("# day part min_ns median_ns mean_ns stddev_ns iterations\n");
+
+    for result in results {
+        if let Some(stats) = result.stats {
+            contents += &format!(
+                "{} {} {} {} {} {} {}\n",
+                result.day,
+                result.part,
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.mean.as_nanos(),
+                stats.stddev.as_nanos(),
+                stats.iterations
+            );
+        }
+    }
+
+    fs::write(path, contents)
+}
+
+// Loads the median times of a run saved by save_bench, by day and part.
+pub fn load_bench(path: &Path) -> io::Result<HashMap<(usize, String), Duration>> {
+    let mut medians = HashMap::new();
+
+    for line in fs::read_to_string(path)?.lines() {
+        if line.starts_with('#') || line.trim().is_empty() {
+            continue;
+        }
+
+        let pieces = line.split_ascii_whitespace().collect::<Vec<&str>>();
+
+        let parsed = match pieces[..] {
+            [day, part, _, median, ..] => day
+                .parse::<usize>()
+                .ok()
+                .zip(median.parse::<u64>().ok())
+                .map(|(day, median)| ((day, part.to_string()), median)),
+            _ => None,
+        };
+
+        let Some((key, median)) = parsed else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Illegal benchmark line [{line}]"),
+            ));
+        };
+
+        medians.insert(key, Duration::from_nanos(median));
+    }
+
+    Ok(medians)
+}
+
+// Prints how each part's median time changed compared to a previously saved run.
+pub fn compare_bench(path: &Path, results: &[PartResult]) -> io::Result<()> {
+    let previous = load_bench(path)?;
+
+    println!("Compared to {}:", path.display());
+
+    for line in median_changes(&previous, results) {
+        println!("  {line}");
+    }
+
+    Ok(())
+}
+
+fn median_changes(
+    previous: &HashMap<(usize, String), Duration>,
+    results: &[PartResult],
+) -> Vec<String> {
+    results
+        .iter()
+        .filter_map(|result| {
+            let stats = result.stats?;
+            let key = (result.day, result.part.to_string());
+
+            Some(match previous.get(&key) {
+                Some(old) => {
+                    let change = (stats.median.as_secs_f64() / old.as_secs_f64() - 1.0) * 100.0;
+
+                    format!(
+                        "Day {} part {}: {:?} -> {:?} ({change:+.1}%)",
+                        result.day, result.part, old, stats.median
+                    )
+                }
+                None => format!(
+                    "Day {} part {}: no previous result",
+                    result.day, result.part
+                ),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+    use crate::{Memo, Part};
+
+    fn secs(samples: &[u64]) -> Vec<Duration> {
+        samples.iter().map(|&s| Duration::from_secs(s)).collect()
+    }
+
+    fn assert_close(actual: Duration, expected: f64) {
+        assert!(
+            (actual.as_secs_f64() - expected).abs() < 1e-6,
+            "{actual:?} isn't {expected}s"
+        );
+    }
+
+    fn result(day: usize, part: Part, stats: Option<BenchStats>) -> PartResult {
+        PartResult {
+            day,
+            part,
+            input: PathBuf::from("input.txt"),
+            answer: Ok("1".to_string()),
+            elapsed: Duration::ZERO,
+            verdict: None,
+            stats,
+        }
+    }
+
+    #[test]
+    fn summarizes_samples() {
+        let stats = BenchStats::from_samples(secs(&[3, 1, 2])).unwrap();
+
+        assert_eq!(stats.min, Duration::from_secs(1));
+        assert_eq!(stats.median, Duration::from_secs(2));
+        assert_close(stats.mean, 2.0);
+        assert_close(stats.stddev, 1.0);
+        assert_eq!(stats.iterations, 3);
+
+        // The median of an even number of runs is halfway between the middle two.
+        let stats = BenchStats::from_samples(secs(&[6, 1, 3, 2])).unwrap();
+
+        assert_eq!(stats.median, Duration::from_millis(2500));
+        assert_close(stats.mean, 3.0);
+        assert_close(stats.stddev, (14.0f64 / 3.0).sqrt());
+
+        let stats = BenchStats::from_samples(secs(&[5])).unwrap();
+
+        assert_eq!(stats.min, Duration::from_secs(5));
+        assert_eq!(stats.median, Duration::from_secs(5));
+        assert_close(stats.mean, 5.0);
+        assert_eq!(stats.stddev, Duration::ZERO);
+
+        assert_eq!(BenchStats::from_samples(vec![]), None);
+    }
+
+    #[test]
+    fn always_measures_a_run() {
+        let options = BenchOptions {
+            warmup: 0,
+            iterations: 0,
+            ..BenchOptions::default()
+        };
+
+        let (answer, stats) = benchmark(|data: &str| data.len(), "abc", &options);
+
+        assert_eq!((answer, stats.iterations), (3, 1));
+        assert_eq!(stats.memo, None);
+    }
+
+    #[test]
+    fn reports_memo_stats() {
+        let options = BenchOptions {
+            warmup: 1,
+            iterations: 3,
+            ..BenchOptions::default()
+        };
+
+        // Only the last run counts, however many runs there were.
+        let (_, stats) = benchmark(
+            |data: &str| {
+                let mut memo = Memo::new();
+
+                for c in data.chars() {
+                    memo.get_or_compute(c, |_| c.is_ascii_digit());
+                }
+            },
+            "1a1b1",
+            &options,
+        );
+
+        let memo = MemoStats {
+            hits: 2,
+            misses: 3,
+            entries: 3,
+        };
+
+        assert_eq!(stats.memo, Some(memo));
+        assert!(stats
+            .to_string()
+            .ends_with(", 3 runs, memo 2 hits, 3 misses (40.0% hit rate), 3 entries"));
+    }
+
+    #[test]
+    fn saves_and_compares() {
+        let path = env::temp_dir().join(format!("aoc-bench-{}.txt", std::process::id()));
+
+        let old = BenchStats::from_samples(secs(&[2])).unwrap();
+        let new = BenchStats::from_samples(secs(&[3])).unwrap();
+
+        save_bench(
+            &path,
+            &[
+                result(1, Part::One, Some(old)),
+                result(1, Part::Two, None),
+                result(2, Part::Two, Some(new)),
+            ],
+        )
+        .unwrap();
+
+        let saved = load_bench(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            saved,
+            HashMap::from([
+                ((1, "1".to_string()), Duration::from_secs(2)),
+                ((2, "2".to_string()), Duration::from_secs(3)),
+            ])
+        );
+
+        let changes = median_changes(
+            &saved,
+            &[
+                result(1, Part::One, Some(new)),
+                result(3, Part::One, Some(new)),
+                result(4, Part::One, None),
+            ],
+        );
+
+        assert_eq!(
+            changes,
+            [
+                "Day 1 part 1: 2s -> 3s (+50.0%)",
+                "Day 3 part 1: no previous result"
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_bad_saves() {
+        let path = env::temp_dir().join(format!("aoc-bench-bad-{}.txt", std::process::id()));
+
+        fs::write(&path, "# comment\n1 1 5\n").unwrap();
+        let e = load_bench(&path).unwrap_err();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(e.to_string(), "Illegal benchmark line [1 1 5]");
+    }
+}
@@ -0,0 +1,621 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::{BenchOptions, OutputFormat};
+
+pub const MAX_DAY: usize = 25;
+pub const FIRST_YEAR: usize = 2015;
+pub const DEFAULT_YEAR: usize = 2023;
+
+pub const USAGE: &str = "Usage: aoc [--year <YEAR>] <COMMAND>
+
+COMMANDS:
+       aoc run <DAYS> [OPTIONS] [--verify]
+       aoc verify <DAYS> [OPTIONS]
+       aoc bench <DAYS> [OPTIONS] [--warmup <N>] [--iterations <N>] [--save <FILE>]
+                 [--compare <FILE>]
+       aoc fetch <DAYS>
+       aoc submit <DAY> <1|2> [ANSWER]
+       aoc import-notes [NOTES]
+       aoc report [--bench <FILE>]
+       aoc new-day <DAY>
+       aoc list
+
+Each year lives in its own yearYYYY/ crate with its puzzles in
+yearYYYY/test_files/, and --year picks one (2023 by default). DAYS can be a
+single day (17), an inclusive range (3..9), a comma separated list of either
+(1,3..5), or all. For run, verify and bench it can also be given with --day.
+
+bench times each part over the measured runs. For the days that cache their
+work in a memo, it also shows the memo's hits and misses in the last run.
+
+fetch downloads the inputs into test_files/dayN/input.txt, using the session
+cookie in AOC_SESSION. Inputs that are already there are kept. submit sends
+ANSWER, or the part's answer for the input, unless test_files/dayN/submissions.txt
+shows it is wrong. Set AOC_URL to use another site than adventofcode.com.
+
+import-notes turns the solve log in NOTES (yearYYYY/notes.txt by default) into
+yearYYYY/journal.toml. report prints the journal, with the current times from a
+file saved by bench --save.
+
+new-day adds src/dayN.rs and placeholders in test_files/dayN/ to the year's
+crate, and registers the day in its src/lib.rs. For a new year it first starts
+the yearYYYY crate, and adds it to Cargo.toml and the years in src/main.rs.
+
+OPTIONS:
+    --day <DAYS>                The days to run, instead of giving DAYS
+    --part <1|2>                Only run one part
+    --input                     Use test_files/dayN/input.txt (the default)
+    --example[=K]               Use test_files/dayN/example.txt, or exampleK.txt
+    --file <PATH|->             Use any file, or stdin for -. Needs a single day
+    --format <text|jsonl|csv>   How to print the results
+    --timeout <SECS>            Give up on a part that runs longer than this
+    --jobs <N>                  Run up to N parts at the same time (default 1)";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    fn from_str(s: &str) -> Result<Self, ArgError> {
+        match s {
+            "1" => Ok(Self::One),
+            "2" => Ok(Self::Two),
+            _ => Err(ArgError::new(format!(
+                "Illegal part [{s}], expected 1 or 2"
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::One => write!(f, "1"),
+            Self::Two => write!(f, "2"),
+        }
+    }
+}
+
+// Where to read the puzzle from: one of the files in test_files/dayN/, any other file, or stdin.
+// The first example is example.txt, and the later ones are example2.txt, example3.txt, ...
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputFile {
+    Input,
+    Example(usize),
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputFile {
+    // The file to read for the given year and day, or None for stdin.
+    pub fn path(&self, year: usize, day: usize) -> Option<PathBuf> {
+        self.path_in(&test_files_dir(year), day)
+    }
+
+    // The file to read for the given day from a test_files directory, or None for stdin.
+    pub fn path_in(&self, test_files: &Path, day: usize) -> Option<PathBuf> {
+        let file_name = match self {
+            Self::Input => "input.txt".to_string(),
+            Self::Example(1) => "example.txt".to_string(),
+            Self::Example(n) => format!("example{n}.txt"),
+            Self::File(path) => return Some(path.clone()),
+            Self::Stdin => return None,
+        };
+
+        Some(test_files.join(format!("day{day}/{file_name}")))
+    }
+}
+
+// The crate that holds a year's solutions, relative to the workspace root.
+pub fn year_dir(year: usize) -> PathBuf {
+    PathBuf::from(format!("year{year}"))
+}
+
+pub fn test_files_dir(year: usize) -> PathBuf {
+    year_dir(year).join("test_files")
+}
+
+// The days given on the command line. All is kept apart from a list of every day, because for run it
+// means every day that has a solution, while a list has to have a solution for each day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Days {
+    All,
+    List(Vec<usize>),
+}
+
+impl Days {
+    // The days, with All as every day of the puzzle.
+    pub fn to_vec(&self) -> Vec<usize> {
+        match self {
+            Self::All => (1..=MAX_DAY).collect(),
+            Self::List(days) => days.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunOptions {
+    pub year: usize,
+    pub days: Days,
+    pub part: Option<Part>,
+    pub input: Option<InputFile>,
+    pub verify: bool,
+    pub bench: Option<BenchOptions>,
+    pub format: OutputFormat,
+    pub timeout: Option<Duration>,
+    pub jobs: usize,
+}
+
+impl RunOptions {
+    pub fn runs_part(&self, part: Part) -> bool {
+        self.part.is_none_or(|p| p == part)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Run(RunOptions),
+    Fetch(Days),
+    Submit {
+        day: usize,
+        part: Part,
+        answer: Option<String>,
+    },
+    // The notes file, when it isn't the year's notes.txt.
+    ImportNotes(Option<PathBuf>),
+    Report(Option<PathBuf>),
+    NewDay(usize),
+    List,
+    Help,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Args {
+    pub year: usize,
+    pub command: Command,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ArgError(String);
+
+impl ArgError {
+    fn new(message: String) -> Self {
+        Self(message)
+    }
+}
+
+impl fmt::Display for ArgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+// Parses the arguments after the program name, e.g. ["run", "3..9", "--part", "2"]. The year can
+// be given anywhere, for any command.
+pub fn parse_args<I>(args: I) -> Result<Args, ArgError>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut args = args.into_iter().collect::<Vec<String>>();
+    let mut year = DEFAULT_YEAR;
+
+    if let Some(i) = args.iter().position(|arg| arg == "--year") {
+        let value = args
+            .get(i + 1)
+            .ok_or_else(|| ArgError::new("Missing value for --year".to_string()))?;
+
+        year = parse_year(value)?;
+        args.drain(i..=i + 1);
+    }
+
+    let command = parse_command(args.into_iter(), year)?;
+
+    Ok(Args { year, command })
+}
+
+fn parse_command(mut args: impl Iterator<Item = String>, year: usize) -> Result<Command, ArgError> {
+    match args.next().as_deref() {
+        Some("run") => parse_run(args, year, false).map(Command::Run),
+        Some("verify") => parse_run(args, year, false).map(|options| {
+            Command::Run(RunOptions {
+                verify: true,
+                ..options
+            })
+        }),
+        Some("bench") => parse_run(args, year, true).map(Command::Run),
+        Some("fetch") => parse_fetch(args).map(Command::Fetch),
+        Some("submit") => parse_submit(args),
+        Some("import-notes") => {
+            let notes = args.next().map(PathBuf::from);
+
+            no_more_args(args).map(|_| Command::ImportNotes(notes))
+        }
+        Some("report") => parse_report(args),
+        Some("new-day") => {
+            let day = parse_day(&next_value(&mut args, "new-day")?)?;
+
+            no_more_args(args).map(|_| Command::NewDay(day))
+        }
+        Some("list") => Ok(Command::List),
+        Some("help") | Some("--help") | Some("-h") | None => Ok(Command::Help),
+        Some(other) => Err(ArgError::new(format!("Unknown command [{other}]"))),
+    }
+}
+
+fn parse_run(
+    args: impl Iterator<Item = String>,
+    year: usize,
+    bench_mode: bool,
+) -> Result<RunOptions, ArgError> {
+    let mut args = args.peekable();
+    let mut days = None;
+    let mut part = None;
+    let mut input = None;
+    let mut verify = false;
+    let mut bench = BenchOptions::default();
+    let mut format = OutputFormat::default();
+    let mut timeout = None;
+    let mut jobs = 1;
+
+    while let Some(arg) = args.next() {
+        let bench_option = matches!(
+            arg.as_str(),
+            "--warmup" | "--iterations" | "--save" | "--compare"
+        );
+
+        if bench_option && !bench_mode {
+            return Err(ArgError::new(format!("{arg} is only allowed with bench")));
+        }
+
+        match arg.as_str() {
+            "--part" => part = Some(Part::from_str(&next_value(&mut args, &arg)?)?),
+            "--day" => {
+                if days.is_some() {
+                    return Err(ArgError::new("Days given twice".to_string()));
+                }
+
+                days = Some(parse_days(&next_value(&mut args, &arg)?)?);
+            }
+            // The example number has to be attached, so that "--example 17" is example.txt for
+            // day 17 rather than example17.txt for every day.
+            "--example" => input = Some(InputFile::Example(1)),
+            _ if arg.starts_with("--example=") => {
+                let number = arg.trim_start_matches("--example=");
+
+                input = Some(InputFile::Example(parse_count(number, true)?));
+            }
+            "--input" => input = Some(InputFile::Input),
+            "--file" => {
+                input = Some(match next_value(&mut args, &arg)?.as_str() {
+                    "-" => InputFile::Stdin,
+                    path => InputFile::File(PathBuf::from(path)),
+                });
+            }
+            "--verify" => verify = true,
+            "--format" => {
+                let value = next_value(&mut args, &arg)?;
+
+                format = OutputFormat::parse(&value)
+                    .ok_or_else(|| ArgError::new(format!("Illegal format [{value}]")))?;
+            }
+            "--timeout" => timeout = Some(parse_timeout(&next_value(&mut args, &arg)?)?),
+            "--jobs" => jobs = parse_count(&next_value(&mut args, &arg)?, true)?,
+            "--warmup" => bench.warmup = parse_count(&next_value(&mut args, &arg)?, false)?,
+            "--iterations" => bench.iterations = parse_count(&next_value(&mut args, &arg)?, true)?,
+            "--save" => bench.save = Some(PathBuf::from(next_value(&mut args, &arg)?)),
+            "--compare" => bench.compare = Some(PathBuf::from(next_value(&mut args, &arg)?)),
+            _ if arg.starts_with("--") => {
+                return Err(ArgError::new(format!("Unknown option [{arg}]")));
+            }
+            _ => {
+                if days.is_some() {
+                    return Err(ArgError::new(format!("Unexpected argument [{arg}]")));
+                }
+
+                days = Some(parse_days(&arg)?);
+            }
+        }
+    }
+
+    let days = days.ok_or_else(|| ArgError::new("No days given".to_string()))?;
+
+    let single_day = matches!(&days, Days::List(days) if days.len() == 1);
+
+    if matches!(input, Some(InputFile::File(_) | InputFile::Stdin)) && !single_day {
+        return Err(ArgError::new("--file needs a single day".to_string()));
+    }
+
+    Ok(RunOptions {
+        year,
+        days,
+        part,
+        input,
+        verify,
+        bench: bench_mode.then_some(bench),
+        format,
+        timeout,
+        jobs,
+    })
+}
+
+fn parse_fetch(mut args: impl Iterator<Item = String>) -> Result<Days, ArgError> {
+    let days = args
+        .next()
+        .ok_or_else(|| ArgError::new("No days given".to_string()))?;
+
+    no_more_args(args)?;
+
+    parse_days(&days)
+}
+
+fn parse_submit(mut args: impl Iterator<Item = String>) -> Result<Command, ArgError> {
+    let day = parse_day(&next_value(&mut args, "submit")?)?;
+    let part = Part::from_str(&next_value(&mut args, "submit")?)?;
+    let answer = args.next();
+
+    no_more_args(args)?;
+
+    Ok(Command::Submit { day, part, answer })
+}
+
+fn parse_report(mut args: impl Iterator<Item = String>) -> Result<Command, ArgError> {
+    let mut bench = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--bench" => bench = Some(PathBuf::from(next_value(&mut args, &arg)?)),
+            _ => return Err(ArgError::new(format!("Unexpected argument [{arg}]"))),
+        }
+    }
+
+    Ok(Command::Report(bench))
+}
+
+fn no_more_args(mut args: impl Iterator<Item = String>) -> Result<(), ArgError> {
+    match args.next() {
+        Some(arg) => Err(ArgError::new(format!("Unexpected argument [{arg}]"))),
+        None => Ok(()),
+    }
+}
+
+fn next_value(args: &mut impl Iterator<Item = String>, option: &str) -> Result<String, ArgError> {
+    args.next()
+        .ok_or_else(|| ArgError::new(format!("Missing value for {option}")))
+}
+
+fn parse_count(s: &str, non_zero: bool) -> Result<usize, ArgError> {
+    match s.parse::<usize>() {
+        Ok(count) if count > 0 || !non_zero => Ok(count),
+        _ => Err(ArgError::new(format!("Illegal count [{s}]"))),
+    }
+}
+
+// Parses a timeout in seconds, which can be fractional (0.5).
+fn parse_timeout(s: &str) -> Result<Duration, ArgError> {
+    match s.parse::<f64>().map(Duration::try_from_secs_f64) {
+        Ok(Ok(timeout)) if !timeout.is_zero() => Ok(timeout),
+        _ => Err(ArgError::new(format!("Illegal timeout [{s}]"))),
+    }
+}
+
+fn parse_year(s: &str) -> Result<usize, ArgError> {
+    match s.parse::<usize>() {
+        Ok(year) if year >= FIRST_YEAR => Ok(year),
+        _ => Err(ArgError::new(format!(
+            "Illegal year [{s}], expected {FIRST_YEAR} or later"
+        ))),
+    }
+}
+
+fn parse_days(s: &str) -> Result<Days, ArgError> {
+    if s == "all" {
+        return Ok(Days::All);
+    }
+
+    let mut days = vec![];
+
+    for piece in s.split(',') {
+        if let Some((start, end)) = piece.split_once("..") {
+            let start = parse_day(start)?;
+            let end = parse_day(end.strip_prefix('=').unwrap_or(end))?;
+
+            if start > end {
+                return Err(ArgError::new(format!("Empty day range [{piece}]")));
+            }
+
+            days.extend(start..=end);
+        } else {
+            days.push(parse_day(piece)?);
+        }
+    }
+
+    days.sort();
+    days.dedup();
+
+    Ok(Days::List(days))
+}
+
+fn parse_day(s: &str) -> Result<usize, ArgError> {
+    match s.parse::<usize>() {
+        Ok(day) if (1..=MAX_DAY).contains(&day) => Ok(day),
+        _ => Err(ArgError::new(format!(
+            "Illegal day [{s}], expected a number from 1 to {MAX_DAY}"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &str) -> Result<Args, ArgError> {
+        parse_args(args.split_whitespace().map(String::from))
+    }
+
+    fn run_options(args: &str) -> RunOptions {
+        match parse(args).unwrap().command {
+            Command::Run(options) => options,
+            command => panic!("Expected a run command, got {command:?}"),
+        }
+    }
+
+    fn error(args: &str) -> String {
+        parse(args).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn parses_days() {
+        assert_eq!(run_options("run 17").days, Days::List(vec![17]));
+        assert_eq!(run_options("run 3..5").days, Days::List(vec![3, 4, 5]));
+        assert_eq!(run_options("run 3..=5").days, Days::List(vec![3, 4, 5]));
+        assert_eq!(
+            run_options("run 5,1,3..4,4").days,
+            Days::List(vec![1, 3, 4, 5])
+        );
+        assert_eq!(run_options("run --day 2").days, Days::List(vec![2]));
+
+        // All is every day with a solution, unlike a list that happens to have every day.
+        assert_eq!(run_options("run all").days, Days::All);
+        assert_eq!(
+            run_options("run 1..25").days,
+            Days::List((1..=MAX_DAY).collect())
+        );
+        assert_eq!(Days::All.to_vec(), (1..=MAX_DAY).collect::<Vec<_>>());
+
+        assert_eq!(
+            error("run 0"),
+            "Illegal day [0], expected a number from 1 to 25"
+        );
+        assert_eq!(
+            error("run 26"),
+            "Illegal day [26], expected a number from 1 to 25"
+        );
+        assert_eq!(error("run 9..3"), "Empty day range [9..3]");
+        assert_eq!(error("run"), "No days given");
+        assert_eq!(error("run 1 --day 2"), "Days given twice");
+        assert_eq!(error("run 1 2"), "Unexpected argument [2]");
+    }
+
+    #[test]
+    fn parses_run_options() {
+        let options = run_options("run 1..3 --part 2 --format csv --jobs 4 --timeout 0.5");
+
+        assert_eq!(options.year, DEFAULT_YEAR);
+        assert_eq!(options.part, Some(Part::Two));
+        assert!(!options.runs_part(Part::One));
+        assert_eq!(options.format, OutputFormat::Csv);
+        assert_eq!(options.jobs, 4);
+        assert_eq!(options.timeout, Some(Duration::from_millis(500)));
+        assert_eq!(options.input, None);
+        assert_eq!(options.bench, None);
+        assert!(!options.verify);
+
+        let options = run_options("run 1");
+        assert_eq!((options.part, options.jobs), (None, 1));
+        assert_eq!(options.format, OutputFormat::Text);
+        assert_eq!(
+            run_options("run 1 --format jsonl").format,
+            OutputFormat::JsonLines
+        );
+
+        assert!(run_options("verify 1").verify);
+        assert!(run_options("run 1 --verify").verify);
+        assert_eq!(run_options("run 1 --input").input, Some(InputFile::Input));
+        assert_eq!(
+            run_options("run 1 --file notes.txt").input,
+            Some(InputFile::File(PathBuf::from("notes.txt")))
+        );
+        assert_eq!(run_options("run 1 --file -").input, Some(InputFile::Stdin));
+
+        // A number after a bare --example is the days, not the example.
+        let options = run_options("run --example 17");
+        assert_eq!(options.input, Some(InputFile::Example(1)));
+        assert_eq!(options.days, Days::List(vec![17]));
+        assert_eq!(
+            run_options("run 17 --example=2").input,
+            Some(InputFile::Example(2))
+        );
+        assert_eq!(error("run 1 --example=0"), "Illegal count [0]");
+        assert_eq!(error("run 1 --example="), "Illegal count []");
+
+        let options = run_options("bench 1 --warmup 0 --iterations 5 --save a.csv");
+        assert_eq!(
+            options.bench,
+            Some(BenchOptions {
+                warmup: 0,
+                iterations: 5,
+                save: Some(PathBuf::from("a.csv")),
+                compare: None,
+            })
+        );
+
+        assert_eq!(error("run 1 --part 3"), "Illegal part [3], expected 1 or 2");
+        assert_eq!(error("run 1 --format xml"), "Illegal format [xml]");
+        assert_eq!(error("run 1 --jobs 0"), "Illegal count [0]");
+        assert_eq!(error("run 1 --timeout 0"), "Illegal timeout [0]");
+        assert_eq!(error("run 1 --jobs"), "Missing value for --jobs");
+        assert_eq!(error("run 1 --fast"), "Unknown option [--fast]");
+        assert_eq!(
+            error("run 1 --warmup 2"),
+            "--warmup is only allowed with bench"
+        );
+        assert_eq!(error("run 1,2 --file -"), "--file needs a single day");
+        assert_eq!(error("run all --file a.txt"), "--file needs a single day");
+    }
+
+    #[test]
+    fn parses_years_and_commands() {
+        // The year can come anywhere.
+        assert_eq!(parse("--year 2015 run 1").unwrap().year, 2015);
+        assert_eq!(run_options("run 1 --year 2016 --part 1").year, 2016);
+        assert_eq!(parse("list").unwrap().year, DEFAULT_YEAR);
+
+        assert_eq!(
+            error("--year 2014 list"),
+            "Illegal year [2014], expected 2015 or later"
+        );
+        assert_eq!(error("list --year"), "Missing value for --year");
+
+        assert_eq!(
+            parse("fetch all").unwrap().command,
+            Command::Fetch(Days::All)
+        );
+        assert_eq!(
+            parse("submit 3 1 42").unwrap().command,
+            Command::Submit {
+                day: 3,
+                part: Part::One,
+                answer: Some("42".to_string())
+            }
+        );
+        assert_eq!(
+            parse("submit 3 2").unwrap().command,
+            Command::Submit {
+                day: 3,
+                part: Part::Two,
+                answer: None
+            }
+        );
+        assert_eq!(
+            parse("import-notes").unwrap().command,
+            Command::ImportNotes(None)
+        );
+        assert_eq!(
+            parse("report --bench b.csv").unwrap().command,
+            Command::Report(Some(PathBuf::from("b.csv")))
+        );
+        assert_eq!(parse("new-day 4").unwrap().command, Command::NewDay(4));
+        assert_eq!(parse("").unwrap().command, Command::Help);
+        assert_eq!(parse("-h").unwrap().command, Command::Help);
+
+        assert_eq!(error("launch"), "Unknown command [launch]");
+        assert_eq!(error("fetch"), "No days given");
+        assert_eq!(error("fetch 1 2"), "Unexpected argument [2]");
+        assert_eq!(error("submit 3"), "Missing value for submit");
+        assert_eq!(error("report 1"), "Unexpected argument [1]");
+    }
+}
@@ -0,0 +1,228 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::Add;
+
+// Where a sequence of states starts repeating: the state after start + length steps is the same
+// as the state after start steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    // The earliest step with the same state as the state after n steps.
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+}
+
+// Follows the states from the initial one until one repeats, remembering every state on the way.
+// The step function is called once per step in order, so it can also advance state of its own.
+pub fn find_cycle<S, F>(initial: S, step: F) -> Cycle
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> S,
+{
+    let mut step = step;
+
+    run(initial, |state| (step(state), ()), usize::MAX)
+        .cycle
+        .expect("the states are finite")
+}
+
+// The state after n steps, which only steps until the states start repeating.
+pub fn state_at<S, F>(initial: S, step: F, n: usize) -> S
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> S,
+{
+    let mut step = step;
+    let mut run = run(initial, |state| (step(state), ()), n);
+
+    let index = run.cycle.map_or(n, |cycle| cycle.equivalent_step(n));
+
+    run.states.swap_remove(index)
+}
+
+// The values the step function returns for each of the first n steps, added up. The value of a
+// step should only depend on the state it steps from.
+pub fn accumulate_at<S, V, F>(initial: S, step: F, n: usize) -> V
+where
+    S: Clone + Eq + Hash,
+    V: Copy + Default + Add<Output = V>,
+    F: FnMut(&S) -> (S, V),
+{
+    let run = run(initial, step, n);
+    let total = |values: &[V]| values.iter().fold(V::default(), |acc, &value| acc + value);
+
+    let Some(cycle) = run.cycle.filter(|cycle| n > cycle.start) else {
+        return total(&run.values[..n]);
+    };
+
+    let repeats = (n - cycle.start) / cycle.length;
+    let remainder = (n - cycle.start) % cycle.length;
+
+    total(&run.values[..cycle.start + remainder])
+        + times(total(&run.values[cycle.start..]), repeats)
+}
+
+// The same as find_cycle, but only ever holds a couple of states using Brent's algorithm. The
+// step function is run from the initial state more than once, so it mustn't have side effects.
+pub fn find_cycle_brent<S, F>(initial: S, mut step: F) -> Cycle
+where
+    S: Clone + Eq,
+    F: FnMut(&S) -> S,
+{
+    // Find the length by moving the tortoise up to the hare at each power of two until the hare
+    // catches it.
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // Then with the hare a cycle ahead, they meet where the cycle starts.
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+
+    let mut start = 0;
+
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle { start, length }
+}
+
+// The same as state_at, but using Brent's algorithm to keep memory bounded.
+pub fn state_at_brent<S, F>(initial: S, mut step: F, n: usize) -> S
+where
+    S: Clone + Eq,
+    F: FnMut(&S) -> S,
+{
+    let cycle = find_cycle_brent(initial.clone(), &mut step);
+
+    (0..cycle.equivalent_step(n)).fold(initial, |state, _| step(&state))
+}
+
+struct Run<S, V> {
+    states: Vec<S>,
+    values: Vec<V>,
+    cycle: Option<Cycle>,
+}
+
+// Steps until a state repeats or n steps have been taken, keeping the states and step values.
+fn run<S, V, F>(initial: S, mut step: F, n: usize) -> Run<S, V>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> (S, V),
+{
+    let mut seen = HashMap::from([(initial.clone(), 0)]);
+    let mut run = Run {
+        states: vec![initial],
+        values: vec![],
+        cycle: None,
+    };
+
+    while run.values.len() < n {
+        let (next, value) = step(&run.states[run.states.len() - 1]);
+
+        run.values.push(value);
+
+        if let Some(&start) = seen.get(&next) {
+            run.cycle = Some(Cycle {
+                start,
+                length: run.states.len() - start,
+            });
+            break;
+        }
+
+        seen.insert(next.clone(), run.states.len());
+        run.states.push(next);
+    }
+
+    run
+}
+
+// Adds the value to itself count times, by doubling.
+fn times<V: Copy + Default + Add<Output = V>>(mut value: V, mut count: usize) -> V {
+    let mut total = V::default();
+
+    while count > 0 {
+        if count % 2 == 1 {
+            total = total + value;
+        }
+
+        value = value + value;
+        count /= 2;
+    }
+
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0, 1, 2, 3, 4, 2, 3, 4, ...
+    fn next(&state: &u32) -> u32 {
+        if state == 4 {
+            2
+        } else {
+            state + 1
+        }
+    }
+
+    #[test]
+    fn finds_cycles() {
+        let expected = Cycle {
+            start: 2,
+            length: 3,
+        };
+
+        assert_eq!(find_cycle(0, next), expected);
+        assert_eq!(find_cycle_brent(0, next), expected);
+        assert_eq!(find_cycle(2, next).start, 0);
+        assert_eq!(
+            find_cycle_brent(7, |_| 7),
+            Cycle {
+                start: 0,
+                length: 1
+            }
+        );
+    }
+
+    #[test]
+    fn extrapolates() {
+        assert_eq!(state_at(0, next, 1), 1);
+        assert_eq!(state_at(0, next, 1_000_000), 4);
+        assert_eq!(state_at_brent(0, next, 1_000_000), 4);
+
+        // Adds up 0 + 1 + 2 + 3 + 4 + 2 + 3 + 4 + 2 + 3.
+        let step = |state: &u32| (next(state), *state);
+        assert_eq!(accumulate_at(0, step, 10), 24);
+        assert_eq!(accumulate_at(0, step, 3), 3);
+        assert_eq!(accumulate_at(0, step, 0), 0);
+    }
+}
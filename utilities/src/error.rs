@@ -0,0 +1,179 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::Section;
+
+pub type PuzzleResult<T> = Result<T, PuzzleError>;
+
+#[derive(Debug)]
+enum ErrorKind {
+    Io { path: PathBuf, source: io::Error },
+    Parse(String),
+    Solve(String),
+    Timeout(Duration),
+}
+
+// An error from loading or solving a puzzle. Parse errors carry the line number, column and the
+// offending text once they are known, and the runner fills in the day.
+#[derive(Debug)]
+pub struct PuzzleError {
+    kind: ErrorKind,
+    day: Option<usize>,
+    file: Option<PathBuf>,
+    line: Option<usize>,
+    column: Option<usize>,
+    text: Option<String>,
+}
+
+impl PuzzleError {
+    fn new(kind: ErrorKind) -> Self {
+        Self {
+            kind,
+            day: None,
+            file: None,
+            line: None,
+            column: None,
+            text: None,
+        }
+    }
+
+    pub fn io(path: &Path, source: io::Error) -> Self {
+        Self::new(ErrorKind::Io {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    pub fn parse(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Parse(message.into()))
+    }
+
+    // The input parsed, but doesn't have the shape the solution relies on.
+    pub fn solve(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Solve(message.into()))
+    }
+
+    pub fn timeout(budget: Duration) -> Self {
+        Self::new(ErrorKind::Timeout(budget))
+    }
+
+    pub fn is_timeout(&self) -> bool {
+        matches!(self.kind, ErrorKind::Timeout(_))
+    }
+
+    // Records the offending text, unless something more specific was already recorded.
+    pub fn with_text(mut self, text: &str) -> Self {
+        self.text.get_or_insert_with(|| text.to_string());
+        self
+    }
+
+    // Records the 1-based line number and its text, unless they were already recorded.
+    pub fn at_line(mut self, line: usize, text: &str) -> Self {
+        self.line.get_or_insert(line);
+        self.with_text(text)
+    }
+
+    // Records the 1-based column in the offending text, unless it was already recorded.
+    pub fn at_column(mut self, column: usize) -> Self {
+        self.column.get_or_insert(column);
+        self
+    }
+
+    // Records which file was being parsed, for errors that aren't about the puzzle input.
+    pub fn in_file(mut self, path: &Path) -> Self {
+        self.file.get_or_insert_with(|| path.to_path_buf());
+        self
+    }
+
+    pub fn in_day(mut self, day: usize) -> Self {
+        self.day.get_or_insert(day);
+        self
+    }
+
+    pub fn day(&self) -> Option<usize> {
+        self.day
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    pub fn column(&self) -> Option<usize> {
+        self.column
+    }
+
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+}
+
+impl fmt::Display for PuzzleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut location = vec![];
+
+        if let Some(day) = self.day {
+            location.push(format!("Day {day}"));
+        }
+
+        if let Some(file) = &self.file {
+            location.push(file.display().to_string());
+        }
+
+        if let Some(line) = self.line {
+            location.push(format!("line {line}"));
+        }
+
+        if let Some(column) = self.column {
+            location.push(format!("column {column}"));
+        }
+
+        if !location.is_empty() {
+            write!(f, "{}: ", location.join(", "))?;
+        }
+
+        match &self.kind {
+            ErrorKind::Io { path, source } => {
+                write!(f, "failed to read {}: {source}", path.display())?
+            }
+            ErrorKind::Parse(message) | ErrorKind::Solve(message) => write!(f, "{message}")?,
+            ErrorKind::Timeout(budget) => write!(f, "Timed out after {budget:?}")?,
+        }
+
+        if let Some(text) = &self.text {
+            write!(f, " in [{text}]")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for PuzzleError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            ErrorKind::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+pub fn parse_number<T: FromStr>(s: &str) -> PuzzleResult<T> {
+    s.trim()
+        .parse::<T>()
+        .map_err(|_| PuzzleError::parse(format!("Illegal number [{s}]")))
+}
+
+// Parses each line, tagging any error with the line it came from.
+pub fn parse_lines<T, F>(data: &str, f: F) -> PuzzleResult<Vec<T>>
+where
+    F: Fn(&str) -> PuzzleResult<T>,
+{
+    Section {
+        first_line: 1,
+        text: data,
+    }
+    .parse_lines(f)
+}
@@ -0,0 +1,114 @@
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::{Answers, InputFile, Part, Registry};
+
+// For the build script of a year's crate: generates one test per recorded example answer, so that
+// test_files/dayN/exampleK.answers holding part_2 = ... gives the test dayN_exampleK_part_2. The
+// tests are written to example_tests.rs in OUT_DIR, to be included next to a check_example
+// function that forwards to the one below.
+pub fn generate_example_tests() {
+    println!("cargo:rerun-if-changed=test_files");
+
+    let mut tests = String::new();
+
+    for (day, example, parts) in example_answers(Path::new("test_files")) {
+        for part in parts {
+            let part_name = if part == 1 { "One" } else { "Two" };
+
+            writeln!(
+                tests,
+                "#[test]\nfn day{day}_example{example}_part_{part}() {{\n    \
+                 check_example({day}, {example}, Part::{part_name});\n}}\n"
+            )
+            .unwrap();
+        }
+    }
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+
+    fs::write(out_dir.join("example_tests.rs"), tests).unwrap();
+}
+
+// The (day, example number, parts with an answer) of every example in the test_files directory,
+// sorted so that the generated file doesn't change between builds.
+fn example_answers(test_files: &Path) -> Vec<(usize, usize, Vec<usize>)> {
+    let mut examples = vec![];
+
+    let Ok(day_dirs) = fs::read_dir(test_files) else {
+        return examples;
+    };
+
+    for day_dir in day_dirs.flatten() {
+        let dir_name = day_dir.file_name().to_string_lossy().to_string();
+
+        let Some(day) = dir_name.strip_prefix("day").and_then(|s| s.parse().ok()) else {
+            continue;
+        };
+
+        for file in fs::read_dir(day_dir.path()).unwrap().flatten() {
+            let file_name = file.file_name().to_string_lossy().to_string();
+
+            let Some(example) = file_name
+                .strip_prefix("example")
+                .and_then(|s| s.strip_suffix(".answers"))
+                .and_then(|s| {
+                    if s.is_empty() {
+                        Some(1)
+                    } else {
+                        s.parse().ok()
+                    }
+                })
+            else {
+                continue;
+            };
+
+            let answers =
+                Answers::load(&file.path().with_extension("txt")).unwrap_or_else(|e| panic!("{e}"));
+
+            let parts = [(1, Part::One), (2, Part::Two)]
+                .into_iter()
+                .filter(|&(_, part)| answers.get(part).is_some())
+                .map(|(number, _)| number)
+                .collect::<Vec<usize>>();
+
+            examples.push((day, example, parts));
+        }
+    }
+
+    examples.sort();
+
+    examples
+}
+
+// Runs one part of a day on one of its example files and compares it with the answer stored next
+// to the file. Tests run in the year's crate, so the files are in test_files/.
+pub fn check_example(registry: &Registry, day: usize, example: usize, part: Part) {
+    let path = InputFile::Example(example)
+        .path_in(Path::new("test_files"), day)
+        .unwrap();
+
+    let data = fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("Failed to read {}: {e}", path.display()));
+
+    let answers = Answers::load(&path).unwrap_or_else(|e| panic!("{e}"));
+    let expected = answers
+        .get(part)
+        .expect("The example has no answer for this part");
+
+    let solution = registry
+        .get(day)
+        .expect("No solution registered for the day");
+
+    let answer = match part {
+        Part::One => solution.part_1(&data),
+        Part::Two => solution.part_2(&data),
+    };
+
+    match answer {
+        Ok(answer) => assert_eq!(answer, expected),
+        Err(e) => panic!("{e}"),
+    }
+}
@@ -0,0 +1,465 @@
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::net::TcpStream;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::{InputFile, DEFAULT_YEAR};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const URL_VAR: &str = "AOC_URL";
+
+pub(crate) const USER_AGENT: &str = "github.com/Monseigneur/AdventOfCode2023";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+// The HTTP layer used by the fetcher and the submitter, so that tests can point them at a local stub
+// server. The body of a POST is an urlencoded form.
+pub trait HttpClient {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> io::Result<HttpResponse>;
+    fn post(&self, url: &str, headers: &[(&str, &str)], form: &str) -> io::Result<HttpResponse>;
+}
+
+// A plain HTTP/1.1 client on top of TcpStream. It doesn't do TLS, so it is only used for http://
+// URLs like a local server.
+pub struct TcpClient;
+
+impl TcpClient {
+    fn send(
+        &self,
+        method: &str,
+        url: &str,
+        headers: &[(&str, &str)],
+        form: Option<&str>,
+    ) -> io::Result<HttpResponse> {
+        let rest = url.strip_prefix("http://").ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Not an http URL [{url}]"),
+            )
+        })?;
+
+        let (host, path) = match rest.find('/') {
+            Some(i) => (&rest[..i], &rest[i..]),
+            None => (rest, "/"),
+        };
+
+        let address = if host.contains(':') {
+            host.to_string()
+        } else {
+            format!("{host}:80")
+        };
+
+        let mut stream = TcpStream::connect(address)?;
+
+        let mut request =
+            format!("{method} {path} HTTP/1.1\r\nHost: {host}\r\nConnection: close\r\n");
+
+        for (name, value) in headers {
+            request += &format!("{name}: {value}\r\n");
+        }
+
+        if let Some(form) = form {
+            request += "Content-Type: application/x-www-form-urlencoded\r\n";
+            request += &format!("Content-Length: {}\r\n\r\n{form}", form.len());
+        } else {
+            request += "\r\n";
+        }
+
+        stream.write_all(request.as_bytes())?;
+
+        // The server closes the connection after the response, so there is no need to look at
+        // Content-Length.
+        let mut response = String::new();
+        stream.read_to_string(&mut response)?;
+
+        parse_response(&response)
+    }
+}
+
+impl HttpClient for TcpClient {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> io::Result<HttpResponse> {
+        self.send("GET", url, headers, None)
+    }
+
+    fn post(&self, url: &str, headers: &[(&str, &str)], form: &str) -> io::Result<HttpResponse> {
+        self.send("POST", url, headers, Some(form))
+    }
+}
+
+fn parse_response(response: &str) -> io::Result<HttpResponse> {
+    let invalid = || io::Error::new(io::ErrorKind::InvalidData, "Malformed HTTP response");
+
+    let (head, body) = response.split_once("\r\n\r\n").ok_or_else(invalid)?;
+
+    let status = head
+        .lines()
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|status| status.parse().ok())
+        .ok_or_else(invalid)?;
+
+    Ok(HttpResponse {
+        status,
+        body: body.to_string(),
+    })
+}
+
+// Runs curl for the real site, since it needs TLS. The headers go through stdin so that the session
+// token doesn't show up in the process list.
+pub struct CurlClient;
+
+impl CurlClient {
+    fn send(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+        form: Option<&str>,
+    ) -> io::Result<HttpResponse> {
+        let mut curl = Command::new("curl");
+
+        curl.args(["--silent", "--show-error", "--header", "@-"])
+            .args(["--write-out", "\n%{http_code}", url]);
+
+        if let Some(form) = form {
+            curl.args(["--data-raw", form]);
+        }
+
+        let mut child = curl
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        let mut stdin = child.stdin.take().unwrap();
+
+        for (name, value) in headers {
+            writeln!(stdin, "{name}: {value}")?;
+        }
+
+        drop(stdin);
+
+        let output = child.wait_with_output()?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+
+            return Err(io::Error::other(format!("curl failed: {}", stderr.trim())));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+
+        let (body, status) = stdout
+            .rsplit_once('\n')
+            .and_then(|(body, status)| Some((body, status.parse().ok()?)))
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "Malformed curl output"))?;
+
+        Ok(HttpResponse {
+            status,
+            body: body.to_string(),
+        })
+    }
+}
+
+impl HttpClient for CurlClient {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> io::Result<HttpResponse> {
+        self.send(url, headers, None)
+    }
+
+    fn post(&self, url: &str, headers: &[(&str, &str)], form: &str) -> io::Result<HttpResponse> {
+        self.send(url, headers, Some(form))
+    }
+}
+
+// Picks the client that can talk to the given site.
+pub fn client_for(base_url: &str) -> Box<dyn HttpClient> {
+    if base_url.starts_with("http://") {
+        Box::new(TcpClient)
+    } else {
+        Box::new(CurlClient)
+    }
+}
+
+// An error from talking to the site, or from the files that keep what it said.
+#[derive(Debug)]
+pub enum SiteError {
+    MissingSession,
+    // The site asks to log in when the session token has expired or is wrong.
+    ExpiredSession,
+    Locked(usize),
+    Http(u16, String),
+    Request(io::Error),
+    Read(PathBuf, io::Error),
+    Write(PathBuf, io::Error),
+    // The answer wasn't submitted, since the history already shows how it would go.
+    Refused(String),
+}
+
+impl fmt::Display for SiteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingSession => write!(f, "Set {SESSION_VAR} to the session cookie"),
+            Self::ExpiredSession => write!(
+                f,
+                "The session token was rejected, it has probably expired. Log in again and \
+                 update {SESSION_VAR}"
+            ),
+            Self::Locked(day) => write!(f, "Day {day} isn't available yet"),
+            Self::Http(status, body) => {
+                write!(f, "Unexpected response {status}: {}", body.trim())
+            }
+            Self::Request(e) => write!(f, "Request failed: {e}"),
+            Self::Read(path, e) => write!(f, "Failed to read {}: {e}", path.display()),
+            Self::Write(path, e) => write!(f, "Failed to write {}: {e}", path.display()),
+            Self::Refused(reason) => write!(f, "Not submitting: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for SiteError {}
+
+pub fn session_from_env() -> Result<String, SiteError> {
+    match env::var(SESSION_VAR) {
+        Ok(session) if !session.trim().is_empty() => Ok(session.trim().to_string()),
+        _ => Err(SiteError::MissingSession),
+    }
+}
+
+// The site to talk to, which can be changed with AOC_URL to test against a local server.
+pub fn base_url_from_env() -> String {
+    env::var(URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string())
+}
+
+pub(crate) fn session_cookie(session: &str) -> String {
+    format!("session={session}")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+// Downloads a year's puzzle inputs into the files the runner reads,
+// yearYYYY/test_files/dayN/input.txt under cache_dir. An input that is already there is never
+// downloaded again, and requests are spaced at least min_interval apart to go easy on the site.
+pub struct Fetcher {
+    client: Box<dyn HttpClient>,
+    session: String,
+    pub year: usize,
+    pub base_url: String,
+    pub cache_dir: PathBuf,
+    pub min_interval: Duration,
+    last_request: Option<Instant>,
+}
+
+impl Fetcher {
+    pub fn new(client: Box<dyn HttpClient>, session: String) -> Self {
+        Self {
+            client,
+            session,
+            year: DEFAULT_YEAR,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            cache_dir: PathBuf::from("."),
+            min_interval: Duration::from_secs(3),
+            last_request: None,
+        }
+    }
+
+    pub fn input_path(&self, day: usize) -> PathBuf {
+        self.cache_dir
+            .join(InputFile::Input.path(self.year, day).unwrap())
+    }
+
+    pub fn fetch(&mut self, day: usize) -> Result<Fetched, SiteError> {
+        let path = self.input_path(day);
+
+        if path.exists() {
+            return Ok(Fetched::Cached(path));
+        }
+
+        let url = format!(
+            "{}/{}/day/{day}/input",
+            self.base_url.trim_end_matches('/'),
+            self.year
+        );
+
+        let response = self.get(&url)?;
+
+        match response.status {
+            200 => {
+                write_cache(&path, &response.body)?;
+
+                Ok(Fetched::Downloaded(path))
+            }
+            404 => Err(SiteError::Locked(day)),
+            400 | 500 if response.body.contains("log in") => Err(SiteError::ExpiredSession),
+            status => Err(SiteError::Http(status, response.body)),
+        }
+    }
+
+    fn get(&mut self, url: &str) -> Result<HttpResponse, SiteError> {
+        if let Some(last_request) = self.last_request {
+            thread::sleep(self.min_interval.saturating_sub(last_request.elapsed()));
+        }
+
+        self.last_request = Some(Instant::now());
+
+        let cookie = session_cookie(&self.session);
+        let headers = [("Cookie", cookie.as_str()), ("User-Agent", USER_AGENT)];
+
+        self.client.get(url, &headers).map_err(SiteError::Request)
+    }
+}
+
+fn write_cache(path: &Path, contents: &str) -> Result<(), SiteError> {
+    let write_error = |e| SiteError::Write(path.to_path_buf(), e);
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(write_error)?;
+    }
+
+    fs::write(path, contents).map_err(write_error)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+
+    // Answers every request with the same response, and remembers the requests. Shared with the
+    // submit tests.
+    pub(crate) fn stub_server(
+        status: u16,
+        body: &'static str,
+    ) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(vec![]));
+
+        let seen = Arc::clone(&requests);
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let request = read_request(&mut stream);
+
+                seen.lock().unwrap().push(request);
+
+                let response = format!(
+                    "HTTP/1.1 {status} Whatever\r\nContent-Length: {}\r\n\r\n{body}",
+                    body.len()
+                );
+
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        (base_url, requests)
+    }
+
+    // The headers, and then the body when there is one, such as a submitted form.
+    fn read_request(stream: &mut impl Read) -> String {
+        let mut request = String::new();
+        let mut buffer = [0; 1024];
+
+        let complete = |request: &str| match request.split_once("\r\n\r\n") {
+            Some((headers, body)) => {
+                let length = headers
+                    .lines()
+                    .filter_map(|line| line.split_once(':'))
+                    .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+                    .map_or(0, |(_, value)| value.trim().parse().unwrap());
+
+                body.len() >= length
+            }
+            None => false,
+        };
+
+        while !complete(&request) {
+            let n = stream.read(&mut buffer).unwrap();
+            request += &String::from_utf8_lossy(&buffer[..n]);
+        }
+
+        request
+    }
+
+    fn fetcher(base_url: &str, name: &str) -> Fetcher {
+        let cache_dir = env::temp_dir().join(format!("aoc-fetch-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&cache_dir);
+
+        let mut fetcher = Fetcher::new(client_for(base_url), "token".to_string());
+        fetcher.base_url = base_url.to_string();
+        fetcher.cache_dir = cache_dir;
+        fetcher.min_interval = Duration::ZERO;
+
+        fetcher
+    }
+
+    #[test]
+    fn downloads_once_and_caches() {
+        let (base_url, requests) = stub_server(200, "1 2 3\n");
+        let mut fetcher = fetcher(&base_url, "cache");
+        fetcher.year = 2015;
+
+        let path = fetcher.input_path(7);
+
+        assert!(path.ends_with("year2015/test_files/day7/input.txt"));
+
+        assert_eq!(fetcher.fetch(7).unwrap(), Fetched::Downloaded(path.clone()));
+        assert_eq!(fetcher.fetch(7).unwrap(), Fetched::Cached(path.clone()));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2 3\n");
+
+        let requests = requests.lock().unwrap();
+
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2015/day/7/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("Cookie: session=token\r\n"));
+    }
+
+    #[test]
+    fn rejected_session_is_expired() {
+        let (base_url, _) = stub_server(
+            400,
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+        );
+        let mut fetcher = fetcher(&base_url, "expired");
+
+        assert!(matches!(fetcher.fetch(1), Err(SiteError::ExpiredSession)));
+        assert!(!fetcher.input_path(1).exists());
+    }
+
+    #[test]
+    fn unreleased_day_is_locked() {
+        let (base_url, _) = stub_server(404, "Please don't repeatedly request this endpoint.");
+        let mut fetcher = fetcher(&base_url, "locked");
+
+        assert!(matches!(fetcher.fetch(25), Err(SiteError::Locked(25))));
+    }
+
+    #[test]
+    fn requests_are_spaced_out() {
+        let (base_url, requests) = stub_server(200, "input");
+        let mut fetcher = fetcher(&base_url, "rate");
+        fetcher.min_interval = Duration::from_millis(200);
+
+        let start = Instant::now();
+
+        fetcher.fetch(1).unwrap();
+        fetcher.fetch(2).unwrap();
+
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        assert_eq!(requests.lock().unwrap().len(), 2);
+    }
+}
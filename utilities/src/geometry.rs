@@ -0,0 +1,241 @@
+use std::fmt;
+
+use crate::{PuzzleError, PuzzleResult};
+
+// A coordinate that points can be moved along, failing instead of wrapping or overflowing.
+pub trait Coordinate: Copy + Ord {
+    fn offset(self, delta: isize) -> Option<Self>;
+
+    fn distance(self, other: Self) -> usize;
+}
+
+impl Coordinate for usize {
+    fn offset(self, delta: isize) -> Option<Self> {
+        self.checked_add_signed(delta)
+    }
+
+    fn distance(self, other: Self) -> usize {
+        self.abs_diff(other)
+    }
+}
+
+impl Coordinate for isize {
+    fn offset(self, delta: isize) -> Option<Self> {
+        self.checked_add(delta)
+    }
+
+    fn distance(self, other: Self) -> usize {
+        self.abs_diff(other)
+    }
+}
+
+impl Coordinate for i64 {
+    fn offset(self, delta: isize) -> Option<Self> {
+        self.checked_add(delta as i64)
+    }
+
+    fn distance(self, other: Self) -> usize {
+        self.abs_diff(other) as usize
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    // Clockwise from up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    // Accepts U/D/L/R and N/E/S/W, in either case.
+    pub fn parse(s: &str) -> PuzzleResult<Self> {
+        match s.to_ascii_uppercase().as_str() {
+            "U" | "N" => Ok(Direction::Up),
+            "R" | "E" => Ok(Direction::Right),
+            "D" | "S" => Ok(Direction::Down),
+            "L" | "W" => Ok(Direction::Left),
+            _ => Err(PuzzleError::parse(format!("Illegal direction [{s}]"))),
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.reverse().turn_right()
+    }
+
+    pub fn reverse(self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::Up | Direction::Down)
+    }
+
+    // The (row, col) step, with rows growing downwards.
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (-1, 0),
+            Direction::Right => (0, 1),
+            Direction::Down => (1, 0),
+            Direction::Left => (0, -1),
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Direction::Up => 'U',
+            Direction::Right => 'R',
+            Direction::Down => 'D',
+            Direction::Left => 'L',
+        };
+
+        write!(f, "{c}")
+    }
+}
+
+// A point on a 2D plane, as a row and column with rows growing downwards. Use usize for grid
+// positions and a signed type for unbounded planes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point<T = usize> {
+    pub row: T,
+    pub col: T,
+}
+
+impl<T: Coordinate> Point<T> {
+    pub fn new(row: T, col: T) -> Self {
+        Self { row, col }
+    }
+
+    // One step in the direction, or None if a coordinate would leave its type's range.
+    pub fn step(self, direction: Direction) -> Option<Self> {
+        self.step_by(direction, 1)
+    }
+
+    pub fn step_by(self, direction: Direction, distance: usize) -> Option<Self> {
+        let (rows, cols) = direction.delta();
+        let distance = isize::try_from(distance).ok()?;
+
+        Some(Self {
+            row: self.row.offset(rows * distance)?,
+            col: self.col.offset(cols * distance)?,
+        })
+    }
+
+    // The up, right, down and left neighbors that are in range.
+    pub fn neighbors(self) -> impl Iterator<Item = Self> {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(direction))
+    }
+
+    // Which way to step to get to an adjacent point.
+    pub fn direction_to(self, other: Self) -> Option<Direction> {
+        Direction::ALL
+            .into_iter()
+            .find(|&direction| self.step(direction) == Some(other))
+    }
+
+    pub fn manhattan(self, other: Self) -> usize {
+        self.row.distance(other.row) + self.col.distance(other.col)
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((row, col): (T, T)) -> Self {
+        Self { row, col }
+    }
+}
+
+impl<T> From<Point<T>> for (T, T) {
+    fn from(point: Point<T>) -> Self {
+        (point.row, point.col)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.row, self.col)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point3<T = i64> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Coordinate> Point3<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn manhattan(self, other: Self) -> usize {
+        self.x.distance(other.x) + self.y.distance(other.y) + self.z.distance(other.z)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns_and_parses() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.reverse(), Direction::Right);
+
+        for (s, direction) in [
+            ("U", Direction::Up),
+            ("E", Direction::Right),
+            ("s", Direction::Down),
+        ] {
+            assert_eq!(Direction::parse(s).unwrap(), direction);
+        }
+
+        assert!(Direction::parse("X").is_err());
+    }
+
+    #[test]
+    fn steps_without_wrapping() {
+        let origin = Point::new(0usize, 0);
+
+        assert_eq!(origin.step(Direction::Up), None);
+        assert_eq!(origin.step_by(Direction::Right, 3), Some(Point::new(0, 3)));
+        assert_eq!(origin.neighbors().count(), 2);
+        assert_eq!(origin.direction_to(Point::new(1, 0)), Some(Direction::Down));
+
+        let signed = Point::new(0isize, 0);
+
+        assert_eq!(signed.step_by(Direction::Up, 2), Some(Point::new(-2, 0)));
+        assert_eq!(signed.manhattan(Point::new(-2, 3)), 5);
+        assert_eq!(Point::new(isize::MIN, 0).step(Direction::Up), None);
+
+        assert_eq!(Point3::new(1i64, 2, 3).manhattan(Point3::new(-1, 2, 0)), 5);
+    }
+}
@@ -0,0 +1,380 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+// The result of a search: the cost of reaching each node that was reached, and the node it was
+// reached from so the paths can be rebuilt. The goal is the first node the search stopped at.
+#[derive(Debug, Clone)]
+pub struct Search<N, C> {
+    costs: HashMap<N, C>,
+    parents: HashMap<N, N>,
+    goal: Option<N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Search<N, C> {
+    fn new() -> Self {
+        Self {
+            costs: HashMap::new(),
+            parents: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.costs.get(node).copied()
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.costs.contains_key(node)
+    }
+
+    pub fn goal(&self) -> Option<&N> {
+        self.goal.as_ref()
+    }
+
+    pub fn goal_cost(&self) -> Option<C> {
+        self.goal.as_ref().and_then(|goal| self.cost(goal))
+    }
+
+    // The nodes reached, with their costs, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&N, C)> {
+        self.costs.iter().map(|(node, &cost)| (node, cost))
+    }
+
+    pub fn len(&self) -> usize {
+        self.costs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.costs.is_empty()
+    }
+
+    // The nodes from the start that led to this one, through to the node itself.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.contains(node) {
+            return None;
+        }
+
+        let mut path = vec![node.clone()];
+
+        while let Some(parent) = self.parents.get(path.last()?) {
+            path.push(parent.clone());
+        }
+
+        path.reverse();
+
+        Some(path)
+    }
+}
+
+// Breadth first search from the starts, where every edge costs 1. Stops at the first node that
+// is_goal accepts, or once everything reachable has been found.
+pub fn bfs<N, S, F, I, G>(starts: S, mut successors: F, mut is_goal: G) -> Search<N, usize>
+where
+    N: Clone + Eq + Hash,
+    S: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    G: FnMut(&N) -> bool,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if !search.contains(&start) {
+            search.costs.insert(start.clone(), 0);
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+
+        let cost = search.costs[&node] + 1;
+
+        for next in successors(&node) {
+            if !search.contains(&next) {
+                search.costs.insert(next.clone(), cost);
+                search.parents.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    search
+}
+
+// Cheapest paths from the starts when the successors come with the cost of getting to them.
+// Stops once the cheapest way to a node that is_goal accepts is known.
+pub fn dijkstra<N, C, S, F, I, G>(starts: S, successors: F, is_goal: G) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    S: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    G: FnMut(&N) -> bool,
+{
+    a_star(starts, successors, |_| C::default(), is_goal)
+}
+
+// Dijkstra guided by a heuristic. Nodes aren't expanded again once settled, so the heuristic has
+// to be consistent for the result to be optimal: it never drops by more than the cost of a step,
+// h(node) <= step + h(next), and is zero at a goal. Not overestimating isn't enough on its own.
+pub fn a_star<N, C, S, F, I, H, G>(
+    starts: S,
+    mut successors: F,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    S: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    H: FnMut(&N) -> C,
+    G: FnMut(&N) -> bool,
+{
+    let mut search = Search::new();
+    let mut settled = HashSet::new();
+
+    // The queue holds indexes into the nodes, so nodes don't need to be ordered. Ties go to the node
+    // queued first.
+    let mut nodes = vec![];
+    let mut queue = BinaryHeap::new();
+
+    for start in starts {
+        if !search.contains(&start) {
+            search.costs.insert(start.clone(), C::default());
+            queue.push((Reverse(heuristic(&start)), Reverse(nodes.len())));
+            nodes.push(start);
+        }
+    }
+
+    while let Some((_, Reverse(index))) = queue.pop() {
+        let node = nodes[index].clone();
+
+        if !settled.insert(node.clone()) {
+            continue;
+        }
+
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+
+        let cost = search.costs[&node];
+
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+
+            if settled.contains(&next) || search.cost(&next).is_some_and(|c| c <= next_cost) {
+                continue;
+            }
+
+            search.costs.insert(next.clone(), next_cost);
+            search.parents.insert(next.clone(), node.clone());
+
+            queue.push((Reverse(next_cost + heuristic(&next)), Reverse(nodes.len())));
+            nodes.push(next);
+        }
+    }
+
+    search
+}
+
+// Orders the nodes, and everything reachable from them, so every node comes before its
+// successors. Fails with a node on a cycle if there is one.
+pub fn topological_sort<N, S, F, I>(nodes: S, mut successors: F) -> Result<Vec<N>, N>
+where
+    N: Clone + Eq + Hash,
+    S: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut sorted = vec![];
+    let mut done = HashSet::new();
+    let mut in_progress = HashSet::new();
+
+    // Depth first, with an explicit stack so long chains don't overflow. Each entry is a node and
+    // its successors that are still to be visited.
+    for node in nodes {
+        if done.contains(&node) {
+            continue;
+        }
+
+        let mut stack = vec![(
+            node.clone(),
+            successors(&node).into_iter().collect::<Vec<N>>(),
+        )];
+        in_progress.insert(node);
+
+        while let Some((node, pending)) = stack.last_mut() {
+            match pending.pop() {
+                Some(next) if in_progress.contains(&next) => return Err(next),
+                Some(next) if done.contains(&next) => (),
+                Some(next) => {
+                    let next_successors = successors(&next).into_iter().collect();
+
+                    in_progress.insert(next.clone());
+                    stack.push((next, next_successors));
+                }
+                None => {
+                    let node = node.clone();
+
+                    in_progress.remove(&node);
+                    done.insert(node.clone());
+                    sorted.push(node);
+                    stack.pop();
+                }
+            }
+        }
+    }
+
+    sorted.reverse();
+
+    Ok(sorted)
+}
+
+// The most expensive paths from the start in a directed acyclic graph. Fails with a node on a cycle
+// if the graph isn't acyclic.
+pub fn dag_longest_paths<N, C, F, I>(start: N, mut successors: F) -> Result<Search<N, C>, N>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let sorted = topological_sort([start.clone()], |node| {
+        successors(node)
+            .into_iter()
+            .map(|(next, _)| next)
+            .collect::<Vec<N>>()
+    })?;
+
+    let mut search = Search::new();
+
+    search.costs.insert(start, C::default());
+
+    for node in sorted {
+        let Some(cost) = search.cost(&node) else {
+            continue;
+        };
+
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+
+            if search.cost(&next).is_none_or(|c| c < next_cost) {
+                search.costs.insert(next.clone(), next_cost);
+                search.parents.insert(next, node.clone());
+            }
+        }
+    }
+
+    Ok(search)
+}
+
+// Groups the nodes that are connected to each other. The neighbors should go both ways.
+pub fn connected_components<N, S, F, I>(nodes: S, mut neighbors: F) -> Vec<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    S: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::new();
+    let mut components = vec![];
+
+    for node in nodes {
+        if seen.contains(&node) {
+            continue;
+        }
+
+        let component = bfs([node], &mut neighbors, |_| false);
+
+        let component = component
+            .costs
+            .into_keys()
+            .inspect(|node| {
+                seen.insert(node.clone());
+            })
+            .collect();
+
+        components.push(component);
+    }
+
+    components
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -> 1 -> 3 costs 1 + 5, 0 -> 2 -> 3 costs 2 + 1, and 4 is on its own.
+    fn edges(node: &u32) -> Vec<(u32, u32)> {
+        match node {
+            0 => vec![(1, 1), (2, 2)],
+            1 => vec![(3, 5)],
+            2 => vec![(3, 1)],
+            _ => vec![],
+        }
+    }
+
+    fn next(node: &u32) -> Vec<u32> {
+        edges(node).into_iter().map(|(next, _)| next).collect()
+    }
+
+    #[test]
+    fn searches() {
+        let search = bfs([0], next, |&node| node == 3);
+        assert_eq!(search.goal_cost(), Some(2));
+        assert_eq!(search.path_to(&3).unwrap().len(), 3);
+        assert!(!search.contains(&4));
+
+        let search = dijkstra([0], edges, |&node| node == 3);
+        assert_eq!(search.goal_cost(), Some(3));
+        assert_eq!(search.path_to(&3), Some(vec![0, 2, 3]));
+
+        let search = a_star([0], edges, |&node| 3 - node.min(3), |&node| node == 3);
+        assert_eq!(search.goal_cost(), Some(3));
+
+        let search = dijkstra([4], edges, |&node| node == 3);
+        assert_eq!(search.goal(), None);
+    }
+
+    #[test]
+    fn sorts_and_finds_longest_paths() {
+        let sorted = topological_sort([0, 4], next).unwrap();
+        let position = |node| sorted.iter().position(|&n| n == node).unwrap();
+
+        assert!(position(0) < position(1) && position(1) < position(3));
+        assert!(position(2) < position(3));
+
+        let longest = dag_longest_paths(0, edges).unwrap();
+        assert_eq!(longest.cost(&3), Some(6));
+        assert_eq!(longest.path_to(&3), Some(vec![0, 1, 3]));
+
+        let cycle = |node: &u32| vec![(node + 1) % 3];
+        assert!(topological_sort([0], cycle).is_err());
+    }
+
+    #[test]
+    fn finds_components() {
+        let undirected = |node: &u32| match node {
+            0 => vec![1],
+            1 => vec![0],
+            _ => vec![],
+        };
+
+        let mut components = connected_components([0, 1, 2], undirected);
+        components.iter_mut().for_each(|component| component.sort());
+        components.sort();
+
+        assert_eq!(components, vec![vec![0, 1], vec![2]]);
+    }
+}
@@ -0,0 +1,411 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::{Direction, Point, PuzzleError, PuzzleResult};
+
+// Steps to the four orthogonal neighbors, clockwise from up, as (row, col) deltas.
+const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+// Steps to all eight neighbors, clockwise from up.
+const ALL: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+// A rectangular grid stored row by row in one buffer, indexed by (row, col).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    // Builds the grid from its rows, which must all be as wide as the first.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> PuzzleResult<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+
+        if width == 0 {
+            return Err(PuzzleError::parse("Empty grid"));
+        }
+
+        let mut cells = Vec::with_capacity(width * height);
+
+        for (i, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(PuzzleError::parse(format!(
+                    "Row {} is {} wide, expected {width}",
+                    i + 1,
+                    row.len()
+                )));
+            }
+
+            cells.extend(row);
+        }
+
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    // Parses one cell per character, tagging any error with the line it came from.
+    pub fn parse_with<F>(data: &str, f: F) -> PuzzleResult<Self>
+    where
+        F: Fn(char) -> PuzzleResult<T>,
+    {
+        let mut width = None;
+        let mut cells = vec![];
+
+        for (i, line) in data.lines().enumerate() {
+            let before = cells.len();
+
+            for c in line.chars() {
+                cells.push(f(c).map_err(|e| e.at_line(i + 1, line))?);
+            }
+
+            let row_width = cells.len() - before;
+
+            if *width.get_or_insert(row_width) != row_width {
+                return Err(PuzzleError::parse(format!(
+                    "Row is {row_width} wide, expected {}",
+                    width.unwrap_or_default()
+                ))
+                .at_line(i + 1, line));
+            }
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Self {
+                width,
+                height: cells.len() / width,
+                cells,
+            }),
+            _ => Err(PuzzleError::parse("Empty grid")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, row: usize, col: usize) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self.contains(row, col)
+            .then(|| &self.cells[row * self.width + col])
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if self.contains(row, col) {
+            Some(&mut self.cells[row * self.width + col])
+        } else {
+            None
+        }
+    }
+
+    // The position steps away from (row, col) by (rows, cols), if it is still in the grid.
+    pub fn step(
+        &self,
+        (row, col): (usize, usize),
+        (rows, cols): (isize, isize),
+    ) -> Option<(usize, usize)> {
+        let row = row.checked_add_signed(rows)?;
+        let col = col.checked_add_signed(cols)?;
+
+        self.contains(row, col).then_some((row, col))
+    }
+
+    // The point next to this one in the direction, if it is in the grid.
+    pub fn neighbor(&self, point: Point, direction: Direction) -> Option<Point> {
+        self.step(point.into(), direction.delta()).map(Point::from)
+    }
+
+    // The up, right, down and left neighbors that are in the grid.
+    pub fn neighbors4(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ORTHOGONAL
+            .iter()
+            .filter_map(move |&delta| self.step(position, delta))
+    }
+
+    // The neighbors including the diagonals that are in the grid.
+    pub fn neighbors8(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ALL.iter()
+            .filter_map(move |&delta| self.step(position, delta))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        // Past the last column, the step would carry on down the next rows.
+        assert!(
+            col < self.width,
+            "Column {col} is outside the {}x{} grid",
+            self.width,
+            self.height
+        );
+
+        self.cells[col..].iter().step_by(self.width)
+    }
+
+    // Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| ((i / self.width, i % self.width), cell))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+
+        (0..self.cells.len()).map(move |i| (i / width, i % width))
+    }
+
+    // The first position, row by row, whose cell matches.
+    pub fn position<P>(&self, predicate: P) -> Option<(usize, usize)>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let i = self.cells.iter().position(predicate)?;
+
+        Some((i / self.width, i % self.width))
+    }
+
+    pub fn find(&self, value: &T) -> Option<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        self.position(|cell| cell == value)
+    }
+
+    pub fn count<P>(&self, mut predicate: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.cells.iter().filter(|cell| predicate(cell)).count()
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |row, col| (col, row))
+    }
+
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |row, col| {
+            (self.height - 1 - col, row)
+        })
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |row, col| {
+            (col, self.width - 1 - row)
+        })
+    }
+
+    // Builds a width x height grid where each cell is copied from source(row, col) in this one.
+    fn rebuild<F>(&self, width: usize, height: usize, source: F) -> Self
+    where
+        T: Clone,
+        F: Fn(usize, usize) -> (usize, usize),
+    {
+        let cells = (0..width * height)
+            .map(|i| {
+                let (row, col) = source(i / width, i % width);
+
+                self[(row, col)].clone()
+            })
+            .collect();
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl Grid<char> {
+    pub fn parse(data: &str) -> PuzzleResult<Self> {
+        Self::parse_with(data, Ok)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(
+            self.contains(row, col),
+            "({row}, {col}) is outside the {}x{} grid",
+            self.width,
+            self.height
+        );
+
+        &self.cells[row * self.width + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(
+            self.contains(row, col),
+            "({row}, {col}) is outside the {}x{} grid",
+            self.width,
+            self.height
+        );
+
+        &mut self.cells[row * self.width + col]
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        &self[(point.row, point.col)]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        &mut self[(point.row, point.col)]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GRID: &str = "ab\ncd\nef\n";
+
+    #[test]
+    fn parses_and_displays() {
+        let grid = Grid::parse(GRID).unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (2, 3));
+        assert_eq!(grid[(2, 1)], 'f');
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.to_string() + "\n", GRID);
+
+        let e = Grid::parse("ab\nc\n").unwrap_err();
+        assert_eq!(e.line(), Some(2));
+
+        let e = Grid::parse_with("12\n3x\n", |c| {
+            c.to_digit(10)
+                .ok_or_else(|| PuzzleError::parse(format!("Illegal digit [{c}]")))
+        })
+        .unwrap_err();
+        assert_eq!(e.line(), Some(2));
+
+        assert!(Grid::parse("").is_err());
+    }
+
+    #[test]
+    fn neighbors_stay_in_the_grid() {
+        let grid = Grid::parse(GRID).unwrap();
+
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbors8((1, 1)).count(), 5);
+        assert_eq!(grid.neighbors8((1, 0)).count(), 5);
+    }
+
+    #[test]
+    fn views_and_rotations() {
+        let grid = Grid::parse(GRID).unwrap();
+
+        assert_eq!(grid.row(1), &['c', 'd']);
+        assert_eq!(grid.column(1).collect::<String>(), "bdf");
+        assert_eq!(grid.find(&'d'), Some((1, 1)));
+
+        assert_eq!(grid.transpose().to_string(), "ace\nbdf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "eca\nfdb");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "bdf\nace");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+    }
+
+    #[test]
+    #[should_panic(expected = "Column 2 is outside the 2x3 grid")]
+    fn column_stays_in_the_grid() {
+        let _ = Grid::parse(GRID).unwrap().column(2);
+    }
+}
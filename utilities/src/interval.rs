@@ -0,0 +1,364 @@
+use std::ops::{Add, Mul, Range, Sub};
+
+// What the intervals need from their bounds, which the primitive integers all provide.
+pub trait Bound: Copy + Ord + Default + Add<Output = Self> + Sub<Output = Self> {}
+
+impl<T: Copy + Ord + Default + Add<Output = T> + Sub<Output = T>> Bound for T {}
+
+// The half-open interval start..end, which is empty when end isn't after start.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Bound> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    // The interval start..=last, for bounds that have a successor.
+    pub fn inclusive(start: T, last: T) -> Self
+    where
+        T: From<u8>,
+    {
+        Self::new(start, last + T::from(1))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    // How many values are in the interval.
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            T::default()
+        } else {
+            self.end - self.start
+        }
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    pub fn overlaps(&self, other: &Self) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    // The values in both, which may be empty.
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    // The values below the given one, and the rest. Either side may be empty.
+    pub fn split_at(&self, value: T) -> (Self, Self) {
+        let value = value.clamp(self.start, self.end.max(self.start));
+
+        (
+            Self::new(self.start, value),
+            Self::new(value, self.end.max(value)),
+        )
+    }
+
+    // Moves the interval up by the delta, which can be negative for signed bounds.
+    pub fn shift(&self, delta: T) -> Self {
+        Self::new(self.start + delta, self.end + delta)
+    }
+}
+
+impl<T> From<Range<T>> for Interval<T> {
+    fn from(range: Range<T>) -> Self {
+        Self {
+            start: range.start,
+            end: range.end,
+        }
+    }
+}
+
+impl<T> IntoIterator for Interval<T>
+where
+    Range<T>: Iterator<Item = T>,
+{
+    type Item = T;
+    type IntoIter = Range<T>;
+
+    fn into_iter(self) -> Range<T> {
+        self.start..self.end
+    }
+}
+
+// A set of values stored as sorted intervals that don't overlap or touch.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct RangeSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Bound> RangeSet<T> {
+    pub fn new() -> Self {
+        Self { intervals: vec![] }
+    }
+
+    // Sorts the intervals and merges the ones that overlap or touch.
+    fn normalized(mut intervals: Vec<Interval<T>>) -> Self {
+        intervals.retain(|interval| !interval.is_empty());
+        intervals.sort();
+
+        let mut merged: Vec<Interval<T>> = Vec::with_capacity(intervals.len());
+
+        for interval in intervals {
+            match merged.last_mut() {
+                Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+                _ => merged.push(interval),
+            }
+        }
+
+        Self { intervals: merged }
+    }
+
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    // How many values are in the set.
+    pub fn len(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::default(), |total, interval| total + interval.len())
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|interval| interval.start)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.intervals
+            .iter()
+            .any(|interval| interval.contains(value))
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        let mut intervals = std::mem::take(&mut self.intervals);
+
+        intervals.push(interval);
+        *self = Self::normalized(intervals);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self::normalized([&self.intervals[..], &other.intervals[..]].concat())
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = vec![];
+        let (mut i, mut j) = (0, 0);
+
+        // Both are sorted, so walk them together, moving on from whichever finishes first.
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let (a, b) = (self.intervals[i], other.intervals[j]);
+            let overlap = a.intersection(&b);
+
+            if !overlap.is_empty() {
+                intervals.push(overlap);
+            }
+
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        Self { intervals }
+    }
+
+    // The values in this set that aren't in the other.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut intervals = vec![];
+
+        for interval in &self.intervals {
+            let mut start = interval.start;
+
+            for removed in &other.intervals {
+                if removed.end <= start || removed.start >= interval.end {
+                    continue;
+                }
+
+                if removed.start > start {
+                    intervals.push(Interval::new(start, removed.start));
+                }
+
+                start = removed.end;
+            }
+
+            if start < interval.end {
+                intervals.push(Interval::new(start, interval.end));
+            }
+        }
+
+        Self { intervals }
+    }
+
+    // The values below the given one, and the rest.
+    pub fn split_at(&self, value: T) -> (Self, Self) {
+        let (below, rest) = self
+            .intervals
+            .iter()
+            .map(|interval| interval.split_at(value))
+            .unzip();
+
+        (Self::normalized(below), Self::normalized(rest))
+    }
+
+    pub fn shift(&self, delta: T) -> Self {
+        Self {
+            intervals: self
+                .intervals
+                .iter()
+                .map(|interval| interval.shift(delta))
+                .collect(),
+        }
+    }
+}
+
+impl<T: Bound> From<Interval<T>> for RangeSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        Self::normalized(vec![interval])
+    }
+}
+
+impl<T: Bound> FromIterator<Interval<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        Self::normalized(iter.into_iter().collect())
+    }
+}
+
+// A box with an interval along each of N axes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cuboid<T, const N: usize> {
+    pub axes: [Interval<T>; N],
+}
+
+impl<T: Bound, const N: usize> Cuboid<T, N> {
+    pub fn new(axes: [Interval<T>; N]) -> Self {
+        Self { axes }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.axes.iter().any(Interval::is_empty)
+    }
+
+    // How many points are in the box.
+    pub fn volume(&self) -> T
+    where
+        T: Mul<Output = T> + From<u8>,
+    {
+        if self.is_empty() {
+            return T::default();
+        }
+
+        self.axes
+            .iter()
+            .fold(T::from(1), |volume, axis| volume * axis.len())
+    }
+
+    pub fn contains(&self, point: [T; N]) -> bool {
+        self.axes
+            .iter()
+            .zip(point)
+            .all(|(axis, value)| axis.contains(value))
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self {
+            axes: std::array::from_fn(|i| self.axes[i].intersection(&other.axes[i])),
+        }
+    }
+
+    // Cuts the box in two along an axis, with the values below the given one on that axis first.
+    pub fn split_at(&self, axis: usize, value: T) -> (Self, Self) {
+        let (below, rest) = self.axes[axis].split_at(value);
+        let (mut first, mut second) = (*self, *self);
+
+        first.axes[axis] = below;
+        second.axes[axis] = rest;
+
+        (first, second)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i32, i32)]) -> RangeSet<i32> {
+        intervals
+            .iter()
+            .map(|&(start, end)| Interval::new(start, end))
+            .collect()
+    }
+
+    #[test]
+    fn intervals() {
+        let interval = Interval::new(2, 6);
+
+        assert_eq!(interval.len(), 4);
+        assert!(interval.contains(2) && !interval.contains(6));
+        assert_eq!(Interval::inclusive(2, 5), interval);
+        assert_eq!(
+            interval.split_at(4),
+            (Interval::new(2, 4), Interval::new(4, 6))
+        );
+        assert!(interval.split_at(9).1.is_empty());
+        assert!(interval.intersection(&Interval::new(6, 8)).is_empty());
+        assert_eq!(interval.shift(-2), Interval::new(0, 4));
+        assert_eq!(Interval::new(5, 1).len(), 0);
+        assert_eq!(Interval::new(1usize, 4).into_iter().sum::<usize>(), 6);
+    }
+
+    #[test]
+    fn sets_are_normalized() {
+        let a = set(&[(5, 8), (0, 2), (2, 3), (7, 10), (4, 4)]);
+
+        assert_eq!(a, set(&[(0, 3), (5, 10)]));
+        assert_eq!(a.len(), 8);
+        assert_eq!(a.min(), Some(0));
+        assert!(a.contains(9) && !a.contains(4));
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[(0, 3), (5, 10)]);
+        let b = set(&[(2, 6), (8, 12)]);
+
+        assert_eq!(a.union(&b), set(&[(0, 12)]));
+        assert_eq!(a.intersection(&b), set(&[(2, 3), (5, 6), (8, 10)]));
+        assert_eq!(a.difference(&b), set(&[(0, 2), (6, 8)]));
+        assert_eq!(b.difference(&a), set(&[(3, 5), (10, 12)]));
+        assert_eq!(a.split_at(6), (set(&[(0, 3), (5, 6)]), set(&[(6, 10)])));
+        assert_eq!(a.shift(10), set(&[(10, 13), (15, 20)]));
+        assert!(a.intersection(&RangeSet::new()).is_empty());
+    }
+
+    #[test]
+    fn cuboids() {
+        let cuboid = Cuboid::new([
+            Interval::new(0, 2),
+            Interval::new(0, 3),
+            Interval::new(1, 2),
+        ]);
+
+        assert_eq!(cuboid.volume(), 6);
+        assert!(cuboid.contains([1, 2, 1]) && !cuboid.contains([1, 2, 2]));
+
+        let (below, rest) = cuboid.split_at(1, 1);
+        assert_eq!((below.volume(), rest.volume()), (2, 4));
+
+        let other = Cuboid::new([Interval::new(1, 5); 3]);
+        assert_eq!(cuboid.intersection(&other).volume(), 2);
+        assert_eq!(cuboid.split_at(0, 0).0.volume(), 0);
+    }
+}
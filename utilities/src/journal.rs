@@ -0,0 +1,644 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::{year_dir, Part, PuzzleError, PuzzleResult};
+
+// Each year keeps its journal, and the notes it was imported from, in its crate.
+pub fn journal_path(year: usize) -> PathBuf {
+    year_dir(year).join("journal.toml")
+}
+
+pub fn notes_path(year: usize) -> PathBuf {
+    year_dir(year).join("notes.txt")
+}
+
+// A wall clock time as written in the journal. There is no time zone, like in notes.txt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LocalTime {
+    pub year: i64,
+    pub month: u32,
+    pub day: u32,
+    pub hour: u32,
+    pub minute: u32,
+}
+
+impl LocalTime {
+    // Parses "2023-12-17 5:36 PM", the format used in notes.txt.
+    fn from_notes(date: &str, time: &str, meridiem: &str) -> Option<Self> {
+        let mut date = date.split('-').map(str::parse::<u32>);
+        let (year, month, day) = (date.next()?.ok()?, date.next()?.ok()?, date.next()?.ok()?);
+
+        let (hour, minute) = time.split_once(':')?;
+        let (hour, minute) = (hour.parse::<u32>().ok()?, minute.parse().ok()?);
+
+        let hour = match (meridiem, hour) {
+            ("AM", 12) => 0,
+            ("AM", 1..=11) => hour,
+            ("PM", 12) => 12,
+            ("PM", 1..=11) => hour + 12,
+            _ => return None,
+        };
+
+        Self::checked(year as i64, month, day, hour, minute)
+    }
+
+    // Parses a TOML local date-time, "2023-12-17T17:36:00".
+    fn from_toml(s: &str) -> Option<Self> {
+        let (date, time) = s.split_once('T')?;
+
+        let mut date = date.split('-').map(str::parse::<u32>);
+        let (year, month, day) = (date.next()?.ok()?, date.next()?.ok()?, date.next()?.ok()?);
+
+        let mut time = time.split(':').map(str::parse::<u32>);
+        let (hour, minute) = (time.next()?.ok()?, time.next()?.ok()?);
+
+        Self::checked(year as i64, month, day, hour, minute)
+    }
+
+    fn checked(year: i64, month: u32, day: u32, hour: u32, minute: u32) -> Option<Self> {
+        let valid =
+            (1..=12).contains(&month) && (1..=31).contains(&day) && hour < 24 && minute < 60;
+
+        valid.then_some(Self {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+        })
+    }
+
+    // Minutes since 1970-01-01 00:00, using the days from civil algorithm.
+    fn minutes(&self) -> i64 {
+        let year = if self.month <= 2 {
+            self.year - 1
+        } else {
+            self.year
+        };
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = (self.month as i64 + 9) % 12;
+        let day_of_year = (153 * month + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        let days = era * 146097 + day_of_era - 719468;
+
+        days * 24 * 60 + self.hour as i64 * 60 + self.minute as i64
+    }
+
+    // The time since the other one, or None if it is before it.
+    fn since(&self, earlier: &LocalTime) -> Option<Duration> {
+        let minutes = self.minutes() - earlier.minutes();
+
+        (minutes >= 0).then(|| Duration::from_secs(minutes as u64 * 60))
+    }
+}
+
+impl fmt::Display for LocalTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:00",
+            self.year, self.month, self.day, self.hour, self.minute
+        )
+    }
+}
+
+// One solved part. The runtime is kept as written ("9.644ms"), since some of the old notes don't
+// have a unit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solve {
+    pub day: usize,
+    pub part: Part,
+    pub solved: LocalTime,
+    pub answer: String,
+    pub runtime: Option<String>,
+    pub rank: Option<u64>,
+    pub notes: String,
+}
+
+impl Solve {
+    pub fn runtime(&self) -> Option<Duration> {
+        parse_runtime(self.runtime.as_deref()?)
+    }
+
+    fn from_table(mut table: HashMap<String, Value>) -> PuzzleResult<Self> {
+        let mut take = |key: &str| table.remove(key);
+        let missing = |key: &str| PuzzleError::parse(format!("Missing or illegal {key}"));
+
+        let day = match take("day") {
+            Some(Value::Integer(day)) => day as usize,
+            _ => return Err(missing("day")),
+        };
+
+        let part = match take("part") {
+            Some(Value::Integer(1)) => Part::One,
+            Some(Value::Integer(2)) => Part::Two,
+            _ => return Err(missing("part")),
+        };
+
+        let solved = match take("solved") {
+            Some(Value::Time(solved)) => solved,
+            _ => return Err(missing("solved")),
+        };
+
+        let answer = match take("answer") {
+            Some(Value::String(answer)) => answer,
+            _ => return Err(missing("answer")),
+        };
+
+        let runtime = match take("runtime") {
+            Some(Value::String(runtime)) => Some(runtime),
+            None => None,
+            _ => return Err(missing("runtime")),
+        };
+
+        let rank = match take("rank") {
+            Some(Value::Integer(rank)) => Some(rank),
+            None => None,
+            _ => return Err(missing("rank")),
+        };
+
+        let notes = match take("notes") {
+            Some(Value::String(notes)) => notes,
+            None => String::new(),
+            _ => return Err(missing("notes")),
+        };
+
+        if let Some(key) = table.keys().next() {
+            return Err(PuzzleError::parse(format!("Unknown key [{key}]")));
+        }
+
+        Ok(Self {
+            day,
+            part,
+            solved,
+            answer,
+            runtime,
+            rank,
+            notes,
+        })
+    }
+}
+
+fn parse_runtime(s: &str) -> Option<Duration> {
+    let split = s.find(|c: char| !c.is_ascii_digit() && c != '.')?;
+    let (number, unit) = s.split_at(split);
+    let number = number.parse::<f64>().ok()?;
+
+    let secs = match unit {
+        "ns" => number / 1e9,
+        "us" | "µs" => number / 1e6,
+        "ms" => number / 1e3,
+        "s" => number,
+        _ => return None,
+    };
+
+    Duration::try_from_secs_f64(secs).ok()
+}
+
+// The solve log, stored as TOML with one [[solve]] table per part:
+//
+//     [[solve]]
+//     day = 16
+//     part = 1
+//     solved = 2023-12-17T17:36:00
+//     answer = "7939"
+//     runtime = "9.644ms"
+//     rank = 27390
+//     notes = "not too bad once I realized how to stop the BFS."
+//
+// Only the TOML used here is supported: comments, [[solve]] headers, and integer, string and local
+// date-time values.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Journal {
+    pub solves: Vec<Solve>,
+}
+
+impl Journal {
+    pub fn parse(data: &str) -> PuzzleResult<Self> {
+        let mut solves = vec![];
+        let mut table: Option<(usize, HashMap<String, Value>)> = None;
+
+        for (i, line) in data.lines().enumerate() {
+            let trimmed = line.trim();
+
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            if trimmed == "[[solve]]" {
+                if let Some((start, table)) = table.take() {
+                    solves.push(
+                        Solve::from_table(table).map_err(|e| e.at_line(start + 1, "[[solve]]"))?,
+                    );
+                }
+
+                table = Some((i, HashMap::new()));
+
+                continue;
+            }
+
+            let entry = trimmed
+                .split_once('=')
+                .ok_or_else(|| PuzzleError::parse("Expected <KEY> = <VALUE>"))
+                .and_then(|(key, value)| Ok((key.trim(), Value::parse(value.trim())?)));
+
+            let (key, value) = entry.map_err(|e| e.at_line(i + 1, line))?;
+
+            let Some((_, table)) = table.as_mut() else {
+                return Err(PuzzleError::parse("Expected [[solve]] first").at_line(i + 1, line));
+            };
+
+            table.insert(key.to_string(), value);
+        }
+
+        if let Some((start, table)) = table {
+            solves.push(Solve::from_table(table).map_err(|e| e.at_line(start + 1, "[[solve]]"))?);
+        }
+
+        Ok(Self { solves })
+    }
+
+    pub fn load(path: &Path) -> PuzzleResult<Self> {
+        let data = fs::read_to_string(path).map_err(|e| PuzzleError::io(path, e))?;
+
+        Self::parse(&data).map_err(|e| e.in_file(path))
+    }
+
+    pub fn to_toml(&self) -> String {
+        let mut toml = String::from("# Solve log, one [[solve]] table per part.\n");
+
+        for solve in &self.solves {
+            toml += &format!(
+                "\n[[solve]]\nday = {}\npart = {}\nsolved = {}\nanswer = {}\n",
+                solve.day,
+                solve.part,
+                solve.solved,
+                quote(&solve.answer)
+            );
+
+            if let Some(runtime) = &solve.runtime {
+                toml += &format!("runtime = {}\n", quote(runtime));
+            }
+
+            if let Some(rank) = solve.rank {
+                toml += &format!("rank = {rank}\n");
+            }
+
+            if !solve.notes.is_empty() {
+                toml += &format!("notes = {}\n", quote(&solve.notes));
+            }
+        }
+
+        toml
+    }
+
+    // Reads the hand written notes.txt format:
+    //
+    //     Day 16:
+    //         - part1: 2023-12-17 5:36 PM [7939, 9.644ms] (27390), not too bad once I realized
+    //                  how to stop the BFS.
+    //
+    // The runtime, the rank and the comment are optional, and indented lines continue the comment.
+    pub fn import_notes(data: &str) -> PuzzleResult<Self> {
+        let mut solves: Vec<Solve> = vec![];
+        let mut day = None;
+
+        for (i, line) in data.lines().enumerate() {
+            let trimmed = line.trim();
+
+            if let Some(number) = trimmed
+                .strip_prefix("Day ")
+                .and_then(|s| s.strip_suffix(':'))
+            {
+                day = Some(crate::parse_number(number).map_err(|e| e.at_line(i + 1, line))?);
+            } else if let Some(entry) = trimmed.strip_prefix("- part") {
+                let Some(day) = day else {
+                    return Err(PuzzleError::parse("Part before any day").at_line(i + 1, line));
+                };
+
+                solves.push(parse_note(day, entry).map_err(|e| e.at_line(i + 1, line))?);
+            } else if line.starts_with(char::is_whitespace) && !trimmed.is_empty() {
+                if let Some(solve) = solves.last_mut() {
+                    solve.notes = format!("{} {trimmed}", solve.notes).trim().to_string();
+                }
+            }
+        }
+
+        Ok(Self { solves })
+    }
+
+    pub fn get(&self, day: usize, part: Part) -> Option<&Solve> {
+        self.solves
+            .iter()
+            .find(|solve| solve.day == day && solve.part == part)
+    }
+}
+
+// Parses what comes after "- part" in notes.txt: "1: 2023-12-1 8:50 AM [54632, 517us] (92963), ...".
+fn parse_note(day: usize, entry: &str) -> PuzzleResult<Solve> {
+    let illegal = || {
+        PuzzleError::parse("Expected partN: <DATE> <TIME> <AM|PM> [<ANSWER>, <RUNTIME>] (<RANK>)")
+    };
+
+    let (part, rest) = entry.split_once(':').ok_or_else(illegal)?;
+
+    let part = match part {
+        "1" => Part::One,
+        "2" => Part::Two,
+        _ => return Err(PuzzleError::parse(format!("Illegal part [{part}]"))),
+    };
+
+    let (when, rest) = rest.split_once('[').ok_or_else(illegal)?;
+    let (result, rest) = rest.split_once(']').ok_or_else(illegal)?;
+
+    let solved = match when.split_whitespace().collect::<Vec<&str>>()[..] {
+        [date, time, meridiem] => LocalTime::from_notes(date, time, meridiem),
+        _ => None,
+    }
+    .ok_or_else(|| PuzzleError::parse(format!("Illegal time [{}]", when.trim())))?;
+
+    let (answer, runtime) = match result.split_once(',') {
+        Some((answer, runtime)) => (answer.trim(), Some(runtime.trim().to_string())),
+        None => (result.trim(), None),
+    };
+
+    let rest = rest.trim();
+
+    let (rank, notes) = match rest.strip_prefix('(').and_then(|s| s.split_once(')')) {
+        Some((rank, notes)) => (Some(crate::parse_number(rank)?), notes),
+        None => (None, rest),
+    };
+
+    Ok(Solve {
+        day,
+        part,
+        solved,
+        answer: answer.to_string(),
+        runtime,
+        rank,
+        notes: notes.trim_start_matches(',').trim().to_string(),
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Value {
+    Integer(u64),
+    String(String),
+    Time(LocalTime),
+}
+
+impl Value {
+    fn parse(s: &str) -> PuzzleResult<Self> {
+        if let Some(quoted) = s.strip_prefix('"') {
+            return unquote(quoted).map(Self::String);
+        }
+
+        if let Some(time) = LocalTime::from_toml(s) {
+            return Ok(Self::Time(time));
+        }
+
+        s.parse()
+            .map(Self::Integer)
+            .map_err(|_| PuzzleError::parse(format!("Illegal value [{s}]")))
+    }
+}
+
+fn quote(s: &str) -> String {
+    let mut quoted = String::from("\"");
+
+    for c in s.chars() {
+        match c {
+            '"' => quoted += "\\\"",
+            '\\' => quoted += "\\\\",
+            '\n' => quoted += "\\n",
+            '\t' => quoted += "\\t",
+            c => quoted.push(c),
+        }
+    }
+
+    quoted + "\""
+}
+
+// Reads a basic string after its opening quote, up to the closing one.
+fn unquote(s: &str) -> PuzzleResult<String> {
+    let mut unquoted = String::new();
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if chars.as_str().trim().is_empty() => return Ok(unquoted),
+            '"' => return Err(PuzzleError::parse("Unexpected text after the string")),
+            '\\' => match chars.next() {
+                Some('"') => unquoted.push('"'),
+                Some('\\') => unquoted.push('\\'),
+                Some('n') => unquoted.push('\n'),
+                Some('t') => unquoted.push('\t'),
+                other => {
+                    return Err(PuzzleError::parse(format!(
+                        "Illegal escape [\\{}]",
+                        other.map(String::from).unwrap_or_default()
+                    )))
+                }
+            },
+            c => unquoted.push(c),
+        }
+    }
+
+    Err(PuzzleError::parse("Unterminated string"))
+}
+
+// Prints a table of every solved part: how long after the puzzle unlocked it was solved, the rank
+// and how it changed from the day before, and the recorded runtime next to the current one from a
+// saved benchmark. The unlock is taken as midnight in the journal's time, so the durations are only
+// as right as the time zone the journal was written in.
+pub fn journal_report(
+    journal: &Journal,
+    year: usize,
+    current: &HashMap<(usize, String), Duration>,
+) -> String {
+    let mut solves = journal.solves.iter().collect::<Vec<&Solve>>();
+    solves.sort_by_key(|solve| (solve.day, solve.part.to_string()));
+
+    let mut lines = vec![format!(
+        "{:>3} {:>4}  {:<16}  {:>12}  {:>12}  {:>7}  {:>8}  {:>12}  {:>12}",
+        "Day",
+        "Part",
+        "Solved",
+        "After unlock",
+        "After part 1",
+        "Rank",
+        "Change",
+        "Recorded",
+        "Current"
+    )];
+
+    for solve in &solves {
+        let unlock = LocalTime {
+            year: year as i64,
+            month: 12,
+            day: solve.day as u32,
+            hour: 0,
+            minute: 0,
+        };
+
+        let after_part_1 = match solve.part {
+            Part::One => None,
+            Part::Two => journal
+                .get(solve.day, Part::One)
+                .and_then(|part_1| solve.solved.since(&part_1.solved)),
+        };
+
+        let previous_rank = (1..solve.day)
+            .rev()
+            .find_map(|day| journal.get(day, solve.part))
+            .and_then(|previous| previous.rank);
+
+        let change = match (solve.rank, previous_rank) {
+            (Some(rank), Some(previous)) => format!("{:+}", rank as i64 - previous as i64),
+            _ => String::new(),
+        };
+
+        let solved = solve.solved.to_string().replace('T', " ");
+
+        lines.push(format!(
+            "{:>3} {:>4}  {:<16}  {:>12}  {:>12}  {:>7}  {:>8}  {:>12}  {:>12}",
+            solve.day,
+            solve.part.to_string(),
+            &solved[..16],
+            format_span(solve.solved.since(&unlock)),
+            format_span(after_part_1),
+            solve.rank.map(|rank| rank.to_string()).unwrap_or_default(),
+            change,
+            solve
+                .runtime()
+                .map(|runtime| format!("{runtime:?}"))
+                .or_else(|| solve.runtime.clone())
+                .unwrap_or_default(),
+            current
+                .get(&(solve.day, solve.part.to_string()))
+                .map(|runtime| format!("{runtime:?}"))
+                .unwrap_or_default(),
+        ));
+    }
+
+    for part in [Part::One, Part::Two] {
+        let ranks = solves
+            .iter()
+            .filter(|solve| solve.part == part)
+            .filter_map(|solve| Some((solve.day, solve.rank?)))
+            .collect::<Vec<(usize, u64)>>();
+
+        if let (Some(first), Some(last)) = (ranks.first(), ranks.last()) {
+            let best = ranks.iter().min_by_key(|(_, rank)| *rank).unwrap();
+
+            lines.push(format!(
+                "Part {part} rank went from {} on day {} to {} on day {}, best {} on day {}",
+                first.1, first.0, last.1, last.0, best.1, best.0
+            ));
+        }
+    }
+
+    let compared = solves
+        .iter()
+        .filter_map(|solve| {
+            let current = current.get(&(solve.day, solve.part.to_string()))?;
+
+            Some((solve.runtime()?, *current))
+        })
+        .collect::<Vec<(Duration, Duration)>>();
+
+    if !compared.is_empty() {
+        let faster = compared
+            .iter()
+            .filter(|(recorded, now)| now < recorded)
+            .count();
+
+        lines.push(format!(
+            "{faster} of {} parts are faster than when they were solved",
+            compared.len()
+        ));
+    }
+
+    lines
+        .iter()
+        .map(|line| line.trim_end())
+        .collect::<Vec<&str>>()
+        .join("\n")
+}
+
+// Formats a duration in days, hours and minutes, like "2d 3h 15m".
+fn format_span(span: Option<Duration>) -> String {
+    let Some(span) = span else {
+        return String::new();
+    };
+
+    let minutes = span.as_secs() / 60;
+    let (days, hours, minutes) = (minutes / (24 * 60), minutes / 60 % 24, minutes % 60);
+
+    if days > 0 {
+        format!("{days}d {hours}h {minutes}m")
+    } else {
+        format!("{hours}h {minutes}m")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOTES: &str = "https://adventofcode.com/
+
+Day 5:
+    - part1: 2023-12-8 6:57 PM [993500720] (85634), hardest part was file parsing
+    - part2: 2023-12-8 9:30 PM [4917124, 32.5s] (61012), easy, but my solution took 32 minutes since I
+             am just brute-forcing it.
+
+Day 25:
+    - part1: 2024-7-5 12:28 AM [601344, 45.203s] (14723)
+";
+
+    #[test]
+    fn imports_notes() {
+        let journal = Journal::import_notes(NOTES).unwrap();
+
+        assert_eq!(journal.solves.len(), 3);
+
+        let solve = journal.get(5, Part::Two).unwrap();
+
+        assert_eq!(solve.solved.to_string(), "2023-12-08T21:30:00");
+        assert_eq!(solve.answer, "4917124");
+        assert_eq!(solve.runtime(), Some(Duration::from_millis(32500)));
+        assert_eq!(solve.rank, Some(61012));
+        assert_eq!(
+            solve.notes,
+            "easy, but my solution took 32 minutes since I am just brute-forcing it."
+        );
+
+        assert_eq!(journal.get(5, Part::One).unwrap().runtime, None);
+        assert_eq!(journal.get(25, Part::One).unwrap().solved.hour, 0);
+        assert_eq!(journal.get(25, Part::One).unwrap().notes, "");
+    }
+
+    #[test]
+    fn toml_round_trip() {
+        let mut journal = Journal::import_notes(NOTES).unwrap();
+        journal.solves[0].notes = "a \"quoted\" \\ note".to_string();
+
+        assert_eq!(Journal::parse(&journal.to_toml()).unwrap(), journal);
+    }
+
+    #[test]
+    fn bad_entries_point_at_the_line() {
+        let e = Journal::import_notes("Day 1:\n    - part1: yesterday [5] (7)\n").unwrap_err();
+
+        assert_eq!(e.line(), Some(2));
+
+        let e = Journal::parse("[[solve]]\nday = 1\npart = 3\n").unwrap_err();
+
+        assert_eq!(e.line(), Some(1));
+    }
+}
@@ -1,34 +1,75 @@
-use std::fs;
+use std::path::PathBuf;
 use std::time::Duration;
-use std::time::Instant;
 
-fn instrument<F, T>(f: F, data: &str) -> (T, Duration)
-where
-    F: Fn(&str) -> T,
-{
-    let now = Instant::now();
-    let result = f(data);
+mod answers;
+mod bench;
+mod cli;
+mod cycle;
+mod error;
+mod examples;
+mod fetch;
+mod geometry;
+mod graph;
+mod grid;
+mod interval;
+mod journal;
+mod math;
+mod matrix;
+mod memo;
+mod output;
+mod parse;
+mod polygon;
+mod pool;
+mod runner;
+mod scaffold;
+mod solution;
+mod submit;
 
-    (result, now.elapsed())
-}
-
-pub fn run_puzzle<A, B, F, G>(day: usize, use_input: bool, f1: F, f2: G)
-where
-    F: Fn(&str) -> A,
-    G: Fn(&str) -> B,
-    A: std::fmt::Display,
-    B: std::fmt::Display,
-{
-    let file_name = if use_input { "input.txt" } else { "example.txt" };
-    let file_path = format!("test_files/day{day}/{file_name}");
-
-    let contents = fs::read_to_string(file_path).unwrap();
+pub use answers::{answers_path, Answers, Verdict};
+pub use bench::{benchmark, compare_bench, load_bench, save_bench, BenchOptions, BenchStats};
+pub use cli::{
+    parse_args, test_files_dir, year_dir, ArgError, Args, Command, Days, InputFile, Part,
+    RunOptions, DEFAULT_YEAR, FIRST_YEAR, MAX_DAY, USAGE,
+};
+pub use cycle::{accumulate_at, find_cycle, find_cycle_brent, state_at, state_at_brent, Cycle};
+pub use error::{parse_lines, parse_number, PuzzleError, PuzzleResult};
+pub use examples::{check_example, generate_example_tests};
+pub use fetch::{
+    base_url_from_env, client_for, session_from_env, CurlClient, Fetched, Fetcher, HttpClient,
+    HttpResponse, SiteError, TcpClient, DEFAULT_BASE_URL, SESSION_VAR, URL_VAR,
+};
+pub use geometry::{Coordinate, Direction, Point, Point3};
+pub use graph::{
+    a_star, bfs, connected_components, dag_longest_paths, dijkstra, topological_sort, Search,
+};
+pub use grid::Grid;
+pub use interval::{Bound, Cuboid, Interval, RangeSet};
+pub use journal::{journal_path, journal_report, notes_path, Journal, LocalTime, Solve};
+pub use math::{crt, extended_gcd, first_in_class, gcd, lcm, mod_inverse, Integer};
+pub use matrix::{Matrix, MatrixError};
+pub use memo::{Memo, MemoStats};
+pub use output::OutputFormat;
+pub use parse::{key_values, sections, Parser, Section};
+pub use polygon::Polygon;
+pub use runner::{run_puzzles, RunSummary};
+pub use scaffold::new_day;
+pub use solution::{Registry, Solution};
+pub use submit::{Attempt, History, Outcome, Submitter};
 
-    let part_1 = instrument(f1, &contents);
-    let part_2 = instrument(f2, &contents);
+pub struct PartResult {
+    pub day: usize,
+    pub part: Part,
+    pub input: PathBuf,
+    pub answer: PuzzleResult<String>,
+    pub elapsed: Duration,
+    pub verdict: Option<Verdict>,
+    pub stats: Option<BenchStats>,
+}
 
-    println!(
-        "[Day {day}]: part 1: {} ({:?}), part 2: {} ({:?})",
-        part_1.0, part_1.1, part_2.0, part_2.1
-    );
-}
\ No newline at end of file
+impl PartResult {
+    // Whether this part should make the run fail: either it didn't produce an answer, or the
+    // answer doesn't match the recorded one.
+    pub fn failed(&self) -> bool {
+        self.answer.is_err() || self.verdict == Some(Verdict::Fail)
+    }
+}
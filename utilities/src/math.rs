@@ -0,0 +1,170 @@
+use std::ops::{Div, Mul, Rem};
+
+// The integer operations gcd and lcm need, so they work on any of the primitive integers.
+pub trait Integer:
+    Copy + Ord + Rem<Output = Self> + Div<Output = Self> + Mul<Output = Self>
+{
+    const ZERO: Self;
+
+    fn abs(self) -> Self;
+}
+
+macro_rules! integer {
+    (unsigned: $($t:ty),*; signed: $($s:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+
+                fn abs(self) -> Self {
+                    self
+                }
+            }
+        )*
+        $(
+            impl Integer for $s {
+                const ZERO: Self = 0;
+
+                fn abs(self) -> Self {
+                    <$s>::abs(self)
+                }
+            }
+        )*
+    };
+}
+
+integer!(unsigned: u8, u16, u32, u64, u128, usize; signed: i8, i16, i32, i64, i128, isize);
+
+// The greatest common divisor, which is never negative. gcd(0, 0) is 0.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a.abs(), b.abs());
+
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+// The least common multiple, which is never negative. It's 0 if either is 0.
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    if a == T::ZERO || b == T::ZERO {
+        return T::ZERO;
+    }
+
+    (a / gcd(a, b) * b).abs()
+}
+
+// Returns (g, x, y) where g = gcd(a, b) and a * x + b * y = g.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let q = old_r / r;
+
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+// The x in 0..m with a * x = 1 (mod m), if a and m are coprime.
+pub fn mod_inverse(a: i128, m: i128) -> Option<i128> {
+    if m <= 0 {
+        return None;
+    }
+
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+// Solves x = residue (mod modulus) for every (residue, modulus) pair with the Chinese Remainder
+// Theorem, where the moduli don't have to be coprime. Returns (x, lcm of the moduli) with x the
+// smallest solution that isn't negative, or None if the congruences contradict each other.
+pub fn crt(congruences: &[(i128, i128)]) -> Option<(i128, i128)> {
+    let mut x: i128 = 0;
+    let mut m: i128 = 1;
+
+    for &(residue, modulus) in congruences {
+        if modulus <= 0 {
+            return None;
+        }
+
+        // x + m * k = residue (mod modulus), which needs gcd(m, modulus) to divide the difference.
+        let (g, inverse, _) = extended_gcd(m, modulus);
+        let difference = residue - x;
+
+        if difference % g != 0 {
+            return None;
+        }
+
+        let step = modulus / g;
+        let k = (difference / g % step * inverse).rem_euclid(step);
+
+        x += m * k;
+        m *= step;
+        x = x.rem_euclid(m);
+    }
+
+    Some((x, m))
+}
+
+// The smallest value that is at least `from` and equal to residue (mod modulus), for a positive
+// modulus. With crt, this is the first solution once every congruence has started.
+pub fn first_in_class(residue: i128, modulus: i128, from: i128) -> i128 {
+    from + (residue - from).rem_euclid(modulus)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(gcd(12usize, 18), 6);
+        assert_eq!(gcd(0u32, 7), 7);
+        assert_eq!(gcd(0i32, 0), 0);
+        assert_eq!(gcd(-12i64, 18), 6);
+        assert_eq!(lcm(4u64, 6), 12);
+        assert_eq!(lcm(-4i32, 6), 12);
+        assert_eq!(lcm(0usize, 6), 0);
+    }
+
+    #[test]
+    fn modular_arithmetic() {
+        let (g, x, y) = extended_gcd(240, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(2, 4), None);
+    }
+
+    #[test]
+    fn chinese_remainders() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // The moduli share a factor of 2.
+        assert_eq!(crt(&[(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt(&[(0, 4), (1, 6)]), None);
+        assert_eq!(crt(&[]), Some((0, 1)));
+    }
+
+    #[test]
+    fn first_in_residue_class() {
+        assert_eq!(first_in_class(2, 5, 0), 2);
+        assert_eq!(first_in_class(2, 5, 2), 2);
+        assert_eq!(first_in_class(2, 5, 3), 7);
+        assert_eq!(first_in_class(2, 5, 13), 17);
+        assert_eq!(first_in_class(23, 5, 0), 3);
+        assert_eq!(first_in_class(-3, 5, -10), -8);
+    }
+}
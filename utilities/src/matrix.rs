@@ -0,0 +1,413 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use num::{BigInt, BigRational, One, Zero};
+
+// Why a matrix operation has no single answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MatrixError {
+    // The sizes don't fit together, as (rows, cols) expected and found.
+    Shape((usize, usize), (usize, usize)),
+    // A square system whose equations aren't independent.
+    Singular,
+    // Fewer independent equations than unknowns, so there are many solutions.
+    Underdetermined { rank: usize, unknowns: usize },
+    // The equations contradict each other, so there's no solution.
+    Inconsistent,
+}
+
+impl fmt::Display for MatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Shape(expected, found) => write!(
+                f,
+                "Expected a {}x{} matrix, found {}x{}",
+                expected.0, expected.1, found.0, found.1
+            ),
+            Self::Singular => write!(f, "The matrix is singular"),
+            Self::Underdetermined { rank, unknowns } => write!(
+                f,
+                "Only {rank} independent equations for {unknowns} unknowns"
+            ),
+            Self::Inconsistent => write!(f, "The equations have no solution"),
+        }
+    }
+}
+
+impl std::error::Error for MatrixError {}
+
+// A dense matrix stored row by row, indexed by (row, col). The linear algebra is done exactly over
+// big rationals, so nothing is lost to rounding or overflow.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matrix<T = BigRational> {
+    rows: usize,
+    cols: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Matrix<T> {
+    // The width is given rather than taken from the rows, so that a system without any equations
+    // still knows how many unknowns it has.
+    pub fn from_rows(width: usize, rows: Vec<Vec<T>>) -> Result<Self, MatrixError> {
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+
+        for row in rows {
+            if row.len() != width {
+                return Err(MatrixError::Shape((height, width), (height, row.len())));
+            }
+
+            cells.extend(row);
+        }
+
+        Ok(Self {
+            rows: height,
+            cols: width,
+            cells,
+        })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        for col in 0..self.cols {
+            self.cells.swap(a * self.cols + col, b * self.cols + col);
+        }
+    }
+}
+
+impl Matrix<BigRational> {
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            cells: vec![BigRational::zero(); rows * cols],
+        }
+    }
+
+    pub fn identity(size: usize) -> Self {
+        let mut matrix = Self::zeros(size, size);
+
+        for i in 0..size {
+            matrix[(i, i)] = BigRational::one();
+        }
+
+        matrix
+    }
+
+    pub fn from_integers<I, R>(width: usize, rows: &[R]) -> Result<Self, MatrixError>
+    where
+        I: Clone + Into<BigInt>,
+        R: AsRef<[I]>,
+    {
+        Self::from_rows(
+            width,
+            rows.iter()
+                .map(|row| {
+                    row.as_ref()
+                        .iter()
+                        .map(|value| BigRational::from_integer(value.clone().into()))
+                        .collect()
+                })
+                .collect(),
+        )
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut transposed = Self::zeros(self.cols, self.rows);
+
+        for row in 0..self.rows {
+            for col in 0..self.cols {
+                transposed[(col, row)] = self[(row, col)].clone();
+            }
+        }
+
+        transposed
+    }
+
+    pub fn mul(&self, other: &Self) -> Result<Self, MatrixError> {
+        if self.cols != other.rows {
+            return Err(MatrixError::Shape(
+                (self.cols, other.cols),
+                (other.rows, other.cols),
+            ));
+        }
+
+        let mut product = Self::zeros(self.rows, other.cols);
+
+        for row in 0..self.rows {
+            for col in 0..other.cols {
+                product[(row, col)] = (0..self.cols)
+                    .map(|i| &self[(row, i)] * &other[(i, col)])
+                    .sum();
+            }
+        }
+
+        Ok(product)
+    }
+
+    // Gauss-Jordan elimination into reduced row echelon form, returning the columns of the pivots.
+    // Only the first `cols` columns are used for pivots, so an augmented column can come along.
+    fn reduce(&mut self, cols: usize) -> Vec<usize> {
+        let mut pivots = vec![];
+
+        for col in 0..cols {
+            let row = pivots.len();
+
+            let Some(pivot) = (row..self.rows).find(|&r| !self[(r, col)].is_zero()) else {
+                continue;
+            };
+
+            self.swap_rows(pivot, row);
+
+            let scale = self[(row, col)].recip();
+            for c in col..self.cols {
+                self[(row, c)] *= &scale;
+            }
+
+            for r in (0..self.rows).filter(|&r| r != row) {
+                let factor = self[(r, col)].clone();
+
+                if factor.is_zero() {
+                    continue;
+                }
+
+                for c in col..self.cols {
+                    let delta = &self[(row, c)] * &factor;
+                    self[(r, c)] -= delta;
+                }
+            }
+
+            pivots.push(col);
+        }
+
+        pivots
+    }
+
+    pub fn reduced_row_echelon(&self) -> Self {
+        let mut reduced = self.clone();
+
+        reduced.reduce(self.cols);
+
+        reduced
+    }
+
+    pub fn rank(&self) -> usize {
+        self.clone().reduce(self.cols).len()
+    }
+
+    pub fn determinant(&self) -> Result<BigRational, MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::Shape(
+                (self.rows, self.rows),
+                (self.rows, self.cols),
+            ));
+        }
+
+        let mut matrix = self.clone();
+        let mut determinant = BigRational::one();
+
+        // Eliminate below each pivot, tracking how swaps flip the sign.
+        for col in 0..self.cols {
+            let Some(pivot) = (col..self.rows).find(|&r| !matrix[(r, col)].is_zero()) else {
+                return Ok(BigRational::zero());
+            };
+
+            if pivot != col {
+                matrix.swap_rows(pivot, col);
+                determinant = -determinant;
+            }
+
+            let value = matrix[(col, col)].clone();
+            determinant *= &value;
+
+            for r in (col + 1)..self.rows {
+                let factor = &matrix[(r, col)] / &value;
+
+                for c in col..self.cols {
+                    let delta = &matrix[(col, c)] * &factor;
+                    matrix[(r, c)] -= delta;
+                }
+            }
+        }
+
+        Ok(determinant)
+    }
+
+    // The x where self * x = b. There can be more equations than unknowns as long as they agree.
+    pub fn solve(&self, b: &[BigRational]) -> Result<Vec<BigRational>, MatrixError> {
+        if b.len() != self.rows {
+            return Err(MatrixError::Shape((self.rows, 1), (b.len(), 1)));
+        }
+
+        let mut augmented = Self::zeros(self.rows, self.cols + 1);
+
+        for row in 0..self.rows {
+            for col in 0..self.cols {
+                augmented[(row, col)] = self[(row, col)].clone();
+            }
+
+            augmented[(row, self.cols)] = b[row].clone();
+        }
+
+        let pivots = augmented.reduce(self.cols);
+        let rank = pivots.len();
+
+        // Whatever is left in the rows without a pivot has to be zero.
+        if (rank..self.rows).any(|row| !augmented[(row, self.cols)].is_zero()) {
+            return Err(MatrixError::Inconsistent);
+        }
+
+        if rank < self.cols {
+            return Err(if self.rows >= self.cols {
+                MatrixError::Singular
+            } else {
+                MatrixError::Underdetermined {
+                    rank,
+                    unknowns: self.cols,
+                }
+            });
+        }
+
+        Ok((0..self.cols)
+            .map(|row| augmented[(row, self.cols)].clone())
+            .collect())
+    }
+
+    // The x that gets self * x closest to b, by solving the normal equations.
+    pub fn least_squares(&self, b: &[BigRational]) -> Result<Vec<BigRational>, MatrixError> {
+        if b.len() != self.rows {
+            return Err(MatrixError::Shape((self.rows, 1), (b.len(), 1)));
+        }
+
+        let transposed = self.transpose();
+        let column = Self::from_rows(1, b.iter().map(|value| vec![value.clone()]).collect())?;
+
+        let normal = transposed.mul(self)?;
+        let target = transposed.mul(&column)?;
+
+        normal.solve(&target.cells)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(
+            row < self.rows && col < self.cols,
+            "({row}, {col}) is outside the {}x{} matrix",
+            self.rows,
+            self.cols
+        );
+
+        &self.cells[row * self.cols + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(
+            row < self.rows && col < self.cols,
+            "({row}, {col}) is outside the {}x{} matrix",
+            self.rows,
+            self.cols
+        );
+
+        &mut self.cells[row * self.cols + col]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rationals(values: &[i64]) -> Vec<BigRational> {
+        values
+            .iter()
+            .map(|&value| BigRational::from_integer(value.into()))
+            .collect()
+    }
+
+    #[test]
+    fn reduces() {
+        let matrix = Matrix::from_integers(3, &[[1, 2, 3], [2, 4, 6], [1, 0, 1]]).unwrap();
+
+        assert_eq!(matrix.rank(), 2);
+        assert_eq!(matrix.determinant(), Ok(BigRational::zero()));
+        assert_eq!(
+            matrix.reduced_row_echelon(),
+            Matrix::from_integers(3, &[[1, 0, 1], [0, 1, 1], [0, 0, 0]]).unwrap()
+        );
+
+        let matrix = Matrix::from_integers(2, &[[0, 2], [3, 1]]).unwrap();
+        assert_eq!(
+            matrix.determinant(),
+            Ok(BigRational::from_integer((-6).into()))
+        );
+        assert_eq!(Matrix::identity(3).rank(), 3);
+
+        assert_eq!(
+            Matrix::from_rows(1, vec![vec![1], vec![1, 2]]),
+            Err(MatrixError::Shape((2, 1), (2, 2)))
+        );
+    }
+
+    #[test]
+    fn solves() {
+        // x + y = 3, x - y = 1, and again scaled up so it's overdetermined but consistent.
+        let matrix = Matrix::from_integers(2, &[[1, 1], [1, -1], [2, 2]]).unwrap();
+        assert_eq!(matrix.solve(&rationals(&[3, 1, 6])), Ok(rationals(&[2, 1])));
+        assert_eq!(
+            matrix.solve(&rationals(&[3, 1, 7])),
+            Err(MatrixError::Inconsistent)
+        );
+
+        let matrix = Matrix::from_integers(2, &[[1, 1], [2, 2]]).unwrap();
+        assert_eq!(
+            matrix.solve(&rationals(&[1, 2])),
+            Err(MatrixError::Singular)
+        );
+
+        let matrix = Matrix::from_integers(3, &[[1, 1, 1]]).unwrap();
+        assert_eq!(
+            matrix.solve(&rationals(&[1])),
+            Err(MatrixError::Underdetermined {
+                rank: 1,
+                unknowns: 3
+            })
+        );
+
+        // Without any equations, every unknown is free.
+        let matrix = Matrix::from_integers::<i64, [i64; 6]>(6, &[]).unwrap();
+        assert_eq!((matrix.rows(), matrix.cols()), (0, 6));
+        assert_eq!(
+            matrix.solve(&[]),
+            Err(MatrixError::Underdetermined {
+                rank: 0,
+                unknowns: 6
+            })
+        );
+
+        // The best line through (0, 0), (1, 1) and (2, 1) is y = 1/6 + x/2.
+        let matrix = Matrix::from_integers(2, &[[1, 0], [1, 1], [1, 2]]).unwrap();
+        let fit = matrix.least_squares(&rationals(&[0, 1, 1])).unwrap();
+        assert_eq!(
+            fit,
+            vec![
+                BigRational::new(1.into(), 6.into()),
+                BigRational::new(1.into(), 2.into())
+            ]
+        );
+    }
+}
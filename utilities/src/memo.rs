@@ -0,0 +1,232 @@
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+use std::ops::Add;
+
+thread_local! {
+    // The stats of every memo dropped on this thread, so the runner can report them for a part
+    // without the solutions having to hand them back.
+    static DROPPED_STATS: Cell<MemoStats> = Cell::new(MemoStats::default());
+}
+
+// The stats of the memos dropped on this thread since the last call.
+pub(crate) fn take_thread_stats() -> MemoStats {
+    DROPPED_STATS.take()
+}
+
+// A cache for a recursive function, which counts how often it saved working something out. The
+// function gets the memo back so that it can look up the smaller cases through it.
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    hits: usize,
+    misses: usize,
+}
+
+impl<K: Hash + Eq, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self {
+            cache: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    // The value for the key from the cache, or from working it out.
+    pub fn get_or_compute(&mut self, key: K, f: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            self.hits += 1;
+            return value.clone();
+        }
+
+        self.misses += 1;
+
+        let value = f(self);
+        self.cache.insert(key, value.clone());
+
+        value
+    }
+
+    // The same for functions that can fail. Errors aren't cached.
+    pub fn try_get_or_compute<E>(
+        &mut self,
+        key: K,
+        f: impl FnOnce(&mut Self) -> Result<V, E>,
+    ) -> Result<V, E> {
+        if let Some(value) = self.cache.get(&key) {
+            self.hits += 1;
+            return Ok(value.clone());
+        }
+
+        self.misses += 1;
+
+        let value = f(self)?;
+        self.cache.insert(key, value.clone());
+
+        Ok(value)
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.cache.get(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        MemoStats {
+            hits: self.hits,
+            misses: self.misses,
+            entries: self.cache.len(),
+        }
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V> Drop for Memo<K, V> {
+    fn drop(&mut self) {
+        let stats = MemoStats {
+            hits: self.hits,
+            misses: self.misses,
+            entries: self.cache.len(),
+        };
+
+        DROPPED_STATS.set(DROPPED_STATS.get() + stats);
+    }
+}
+
+// How a memo has been used. Every miss is one call that had to be worked out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MemoStats {
+    pub hits: usize,
+    pub misses: usize,
+    pub entries: usize,
+}
+
+impl MemoStats {
+    pub fn lookups(&self) -> usize {
+        self.hits + self.misses
+    }
+
+    // The fraction of lookups that were answered from the cache.
+    pub fn hit_rate(&self) -> f64 {
+        if self.lookups() == 0 {
+            0.0
+        } else {
+            self.hits as f64 / self.lookups() as f64
+        }
+    }
+}
+
+impl Add for MemoStats {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            hits: self.hits + other.hits,
+            misses: self.misses + other.misses,
+            entries: self.entries + other.entries,
+        }
+    }
+}
+
+impl fmt::Display for MemoStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses ({:.1}% hit rate), {} entries",
+            self.hits,
+            self.misses,
+            self.hit_rate() * 100.0,
+            self.entries
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fibonacci(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+        if n < 2 {
+            return n;
+        }
+
+        memo.get_or_compute(n, |memo| fibonacci(memo, n - 1) + fibonacci(memo, n - 2))
+    }
+
+    #[test]
+    fn caches_recursive_calls() {
+        let mut memo = Memo::new();
+
+        assert_eq!(fibonacci(&mut memo, 90), 2880067194370816120);
+
+        // Each n from 2 to 90 is worked out once, and fib(n - 2) is then found in the cache.
+        let stats = memo.stats();
+        assert_eq!(stats.misses, 89);
+        assert_eq!(stats.hits, 87);
+        assert_eq!(stats.entries, 89);
+        assert_eq!(memo.get(&10), Some(&55));
+
+        fibonacci(&mut memo, 90);
+        assert_eq!(memo.stats().hits, 88);
+
+        memo.clear();
+        assert!(memo.is_empty());
+        assert_eq!(
+            MemoStats::default().to_string(),
+            "0 hits, 0 misses (0.0% hit rate), 0 entries"
+        );
+    }
+
+    #[test]
+    fn adds_up_dropped_memos() {
+        take_thread_stats();
+
+        for n in [10, 20] {
+            fibonacci(&mut Memo::new(), n);
+        }
+
+        assert_eq!(
+            take_thread_stats(),
+            MemoStats {
+                hits: 7 + 17,
+                misses: 9 + 19,
+                entries: 9 + 19
+            }
+        );
+        assert_eq!(take_thread_stats(), MemoStats::default());
+    }
+
+    #[test]
+    fn does_not_cache_errors() {
+        let mut memo: Memo<u32, u32> = Memo::new();
+
+        assert_eq!(memo.try_get_or_compute(1, |_| Err("no")), Err("no"));
+        assert_eq!(memo.try_get_or_compute(1, |_| Ok::<_, &str>(5)), Ok(5));
+        assert_eq!(memo.try_get_or_compute(1, |_| Err("no")), Ok(5));
+        assert_eq!(
+            memo.stats(),
+            MemoStats {
+                hits: 1,
+                misses: 2,
+                entries: 1
+            }
+        );
+    }
+}
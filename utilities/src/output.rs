@@ -0,0 +1,237 @@
+use crate::PartResult;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    JsonLines,
+    Csv,
+}
+
+const CSV_HEADER: &str = "day,part,answer,elapsed_ns,input,status,error";
+
+impl OutputFormat {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "text" => Some(Self::Text),
+            "jsonl" | "json" => Some(Self::JsonLines),
+            "csv" => Some(Self::Csv),
+            _ => None,
+        }
+    }
+
+    // The line to print before any results.
+    pub fn header(&self, year: usize) -> String {
+        match self {
+            Self::Text => format!("Advent of code {year}"),
+            Self::JsonLines => String::new(),
+            Self::Csv => CSV_HEADER.to_string(),
+        }
+    }
+
+    // Formats all of the results for a single day. Text puts the whole day on one line, the other
+    // formats have one record per part.
+    pub fn format_day(&self, day: usize, results: &[PartResult]) -> String {
+        match self {
+            Self::Text => format_text(day, results),
+            Self::JsonLines => results
+                .iter()
+                .map(format_json)
+                .collect::<Vec<_>>()
+                .join("\n"),
+            Self::Csv => results
+                .iter()
+                .map(format_csv)
+                .collect::<Vec<_>>()
+                .join("\n"),
+        }
+    }
+}
+
+fn format_text(day: usize, results: &[PartResult]) -> String {
+    let summary = results
+        .iter()
+        .map(|result| {
+            let answer = match &result.answer {
+                Ok(answer) => answer,
+                Err(e) if e.is_timeout() => return format!("part {}: TIMEOUT", result.part),
+                Err(_) => return format!("part {}: ERROR", result.part),
+            };
+
+            let mut s = match result.stats {
+                Some(stats) => format!("part {}: {answer} ({stats})", result.part),
+                None => format!("part {}: {answer} ({:?})", result.part, result.elapsed),
+            };
+
+            if let Some(verdict) = result.verdict {
+                s += &format!(" {verdict}");
+            }
+
+            s
+        })
+        .collect::<Vec<String>>();
+
+    format!("[Day {day}]: {}", summary.join(", "))
+}
+
+// The status column: the verdict when verifying, or TIMEOUT or ERROR when the part didn't produce
+// an answer.
+fn status(result: &PartResult) -> Option<String> {
+    match (&result.answer, result.verdict) {
+        (Err(e), _) if e.is_timeout() => Some("TIMEOUT".to_string()),
+        (Err(_), _) => Some("ERROR".to_string()),
+        (Ok(_), verdict) => verdict.map(|v| v.to_string()),
+    }
+}
+
+fn format_json(result: &PartResult) -> String {
+    let json_or_null = |s: Option<String>| s.map_or("null".to_string(), |s| json_string(&s));
+
+    let (answer, error) = match &result.answer {
+        Ok(answer) => (Some(answer.clone()), None),
+        Err(e) => (None, Some(e.to_string())),
+    };
+
+    format!(
+        "{{\"day\":{},\"part\":{},\"answer\":{},\"elapsed_ns\":{},\"input\":{},\"status\":{},\"error\":{}}}",
+        result.day,
+        result.part,
+        json_or_null(answer),
+        result.elapsed.as_nanos(),
+        json_string(&result.input.display().to_string()),
+        json_or_null(status(result)),
+        json_or_null(error)
+    )
+}
+
+fn json_string(s: &str) -> String {
+    let mut escaped = String::from("\"");
+
+    for c in s.chars() {
+        match c {
+            '"' => escaped += "\\\"",
+            '\\' => escaped += "\\\\",
+            '\n' => escaped += "\\n",
+            '\r' => escaped += "\\r",
+            '\t' => escaped += "\\t",
+            c if c.is_control() => escaped += &format!("\\u{:04x}", c as u32),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped.push('"');
+
+    escaped
+}
+
+fn format_csv(result: &PartResult) -> String {
+    let (answer, error) = match &result.answer {
+        Ok(answer) => (csv_field(answer), String::new()),
+        Err(e) => (String::new(), csv_field(&e.to_string())),
+    };
+
+    [
+        result.day.to_string(),
+        result.part.to_string(),
+        answer,
+        result.elapsed.as_nanos().to_string(),
+        csv_field(&result.input.display().to_string()),
+        status(result).unwrap_or_default(),
+        error,
+    ]
+    .join(",")
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    use super::*;
+    use crate::{Part, PuzzleError, PuzzleResult, Verdict};
+
+    fn result(part: Part, answer: PuzzleResult<&str>, verdict: Option<Verdict>) -> PartResult {
+        PartResult {
+            day: 7,
+            part,
+            input: PathBuf::from("test_files/day7/input.txt"),
+            answer: answer.map(str::to_string),
+            elapsed: Duration::from_nanos(1500),
+            verdict,
+            stats: None,
+        }
+    }
+
+    fn results() -> Vec<PartResult> {
+        vec![
+            result(Part::One, Ok("6440"), Some(Verdict::Pass)),
+            result(
+                Part::Two,
+                Err(PuzzleError::parse("Illegal card [\"X\"]").at_line(2, "32T3K,765\tX")),
+                None,
+            ),
+        ]
+    }
+
+    #[test]
+    fn formats_text() {
+        let mut results = results();
+        results.push(result(
+            Part::Two,
+            Err(PuzzleError::timeout(Duration::from_secs(1))),
+            None,
+        ));
+
+        assert_eq!(OutputFormat::Text.header(2023), "Advent of code 2023");
+        assert_eq!(
+            OutputFormat::Text.format_day(7, &results),
+            "[Day 7]: part 1: 6440 (1.5µs) PASS, part 2: ERROR, part 2: TIMEOUT"
+        );
+    }
+
+    #[test]
+    fn formats_json_lines() {
+        assert_eq!(OutputFormat::JsonLines.header(2023), "");
+        assert_eq!(
+            OutputFormat::JsonLines.format_day(7, &results()),
+            [
+                r#"{"day":7,"part":1,"answer":"6440","elapsed_ns":1500,"input":"test_files/day7/input.txt","status":"PASS","error":null}"#,
+                r#"{"day":7,"part":2,"answer":null,"elapsed_ns":1500,"input":"test_files/day7/input.txt","status":"ERROR","error":"line 2: Illegal card [\"X\"] in [32T3K,765\tX]"}"#,
+            ]
+            .join("\n")
+        );
+
+        assert_eq!(json_string("a\\b\n\r\u{1}é"), r#""a\\b\n\r\u0001é""#);
+    }
+
+    #[test]
+    fn formats_csv() {
+        let mut results = results();
+        results.push(PartResult {
+            input: PathBuf::from("my, input.txt"),
+            ..result(Part::One, Ok("two\nlines"), None)
+        });
+
+        assert_eq!(
+            OutputFormat::Csv.header(2023),
+            "day,part,answer,elapsed_ns,input,status,error"
+        );
+        assert_eq!(
+            OutputFormat::Csv.format_day(7, &results),
+            [
+                "7,1,6440,1500,test_files/day7/input.txt,PASS,",
+                "7,2,,1500,test_files/day7/input.txt,ERROR,\"line 2: Illegal card [\"\"X\"\"] in [32T3K,765\tX]\"",
+                "7,1,\"two\nlines\",1500,\"my, input.txt\",,",
+            ]
+            .join("\n")
+        );
+    }
+}
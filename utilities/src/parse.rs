@@ -0,0 +1,338 @@
+use std::str::FromStr;
+
+use crate::{PuzzleError, PuzzleResult};
+
+// A cursor over a line of input, for parsing it a token at a time. Whitespace before each token is
+// skipped, and errors point at the column where parsing got stuck.
+#[derive(Debug, Clone, Copy)]
+pub struct Parser<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self { text, position: 0 }
+    }
+
+    // Runs the parser over the whole text, which has to be used up.
+    pub fn parse<T>(
+        text: &'a str,
+        f: impl FnOnce(&mut Self) -> PuzzleResult<T>,
+    ) -> PuzzleResult<T> {
+        let mut parser = Self::new(text);
+        let value = f(&mut parser)?;
+
+        parser.end()?;
+
+        Ok(value)
+    }
+
+    pub fn rest(&self) -> &'a str {
+        &self.text[self.position..]
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.rest().trim().is_empty()
+    }
+
+    // A parse error at the current position.
+    pub fn error(&self, message: impl Into<String>) -> PuzzleError {
+        let column = self.text[..self.position].chars().count() + 1;
+
+        PuzzleError::parse(message)
+            .at_column(column)
+            .with_text(self.text)
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    // Moves past the token if it comes next.
+    pub fn try_token(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+
+        if self.rest().starts_with(token) {
+            self.position += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn token(&mut self, token: &str) -> PuzzleResult<()> {
+        if self.try_token(token) {
+            Ok(())
+        } else {
+            Err(self.error(format!("Expected [{token}]")))
+        }
+    }
+
+    // Whichever of the tokens comes next, trying them in order.
+    pub fn one_of<'t>(&mut self, tokens: &[&'t str]) -> PuzzleResult<&'t str> {
+        tokens
+            .iter()
+            .find(|token| self.try_token(token))
+            .copied()
+            .ok_or_else(|| self.error(format!("Expected one of [{}]", tokens.join(", "))))
+    }
+
+    // The characters up to the first one that doesn't match, which may be none.
+    pub fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        self.skip_whitespace();
+
+        let rest = self.rest();
+        let len = rest.find(|c| !predicate(c)).unwrap_or(rest.len());
+        self.position += len;
+
+        &rest[..len]
+    }
+
+    // A name made of letters, digits and underscores.
+    pub fn word(&mut self) -> PuzzleResult<&'a str> {
+        let word = self.take_while(|c| c.is_alphanumeric() || c == '_');
+
+        if word.is_empty() {
+            Err(self.error("Expected a name"))
+        } else {
+            Ok(word)
+        }
+    }
+
+    // A whole number, which can have a sign.
+    pub fn int<T: FromStr>(&mut self) -> PuzzleResult<T> {
+        self.skip_whitespace();
+
+        let rest = self.rest();
+        let sign = usize::from(rest.starts_with(['+', '-']));
+        let digits = rest[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len() - sign);
+
+        if digits == 0 {
+            return Err(self.error("Expected a number"));
+        }
+
+        let number = &rest[..sign + digits];
+        let value = number
+            .parse()
+            .map_err(|_| self.error(format!("Illegal number [{number}]")))?;
+        self.position += number.len();
+
+        Ok(value)
+    }
+
+    // A name, the separator, and then the value.
+    pub fn key_value<T>(
+        &mut self,
+        separator: &str,
+        value: impl FnOnce(&mut Self) -> PuzzleResult<T>,
+    ) -> PuzzleResult<(&'a str, T)> {
+        let key = self.word()?;
+        self.token(separator)?;
+
+        Ok((key, value(self)?))
+    }
+
+    // One or more items with the separator between them.
+    pub fn list<T>(
+        &mut self,
+        separator: &str,
+        mut item: impl FnMut(&mut Self) -> PuzzleResult<T>,
+    ) -> PuzzleResult<Vec<T>> {
+        let mut items = vec![item(self)?];
+
+        while self.try_token(separator) {
+            items.push(item(self)?);
+        }
+
+        Ok(items)
+    }
+
+    // Items for as long as they parse, which may be none.
+    pub fn many<T>(&mut self, mut item: impl FnMut(&mut Self) -> PuzzleResult<T>) -> Vec<T> {
+        let mut items = vec![];
+
+        loop {
+            let start = self.position;
+
+            match self.optional(&mut item) {
+                // An item that took nothing would match forever.
+                Some(value) if self.position > start => items.push(value),
+                _ => return items,
+            }
+        }
+    }
+
+    // Tries the item, going back to where it started if it doesn't parse.
+    pub fn optional<T>(&mut self, item: impl FnOnce(&mut Self) -> PuzzleResult<T>) -> Option<T> {
+        let start = self.position;
+        let value = item(self);
+
+        if value.is_err() {
+            self.position = start;
+        }
+
+        value.ok()
+    }
+
+    pub fn end(&mut self) -> PuzzleResult<()> {
+        self.skip_whitespace();
+
+        if self.rest().is_empty() {
+            Ok(())
+        } else {
+            Err(self.error(format!("Unexpected [{}]", self.rest())))
+        }
+    }
+}
+
+// A run of lines between blank lines, and the 1-based number of its first line in the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Section<'a> {
+    pub first_line: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Section<'a> {
+    // Each line with its number in the whole input.
+    pub fn lines(&self) -> impl Iterator<Item = (usize, &'a str)> + '_ {
+        self.text
+            .lines()
+            .enumerate()
+            .map(|(i, line)| (self.first_line + i, line))
+    }
+
+    // Parses each line, tagging any error with the line it came from.
+    pub fn parse_lines<T>(&self, f: impl Fn(&'a str) -> PuzzleResult<T>) -> PuzzleResult<Vec<T>> {
+        self.lines()
+            .map(|(number, line)| f(line).map_err(|e| e.at_line(number, line)))
+            .collect()
+    }
+}
+
+// Splits the input on blank lines. Runs of several blank lines don't make empty sections.
+pub fn sections(data: &str) -> Vec<Section<'_>> {
+    let mut sections = vec![];
+    let mut current: Option<(usize, usize, usize)> = None;
+    let mut offset = 0;
+
+    for (i, chunk) in data.split_inclusive('\n').enumerate() {
+        let line = chunk.trim_end_matches(['\n', '\r']);
+
+        if line.trim().is_empty() {
+            if let Some((first_line, start, end)) = current.take() {
+                sections.push(Section {
+                    first_line,
+                    text: &data[start..end],
+                });
+            }
+        } else {
+            let end = offset + line.len();
+            current.get_or_insert((i + 1, offset, end)).2 = end;
+        }
+
+        offset += chunk.len();
+    }
+
+    if let Some((first_line, start, end)) = current {
+        sections.push(Section {
+            first_line,
+            text: &data[start..end],
+        });
+    }
+
+    sections
+}
+
+// Parses lines of <KEY><SEPARATOR><VALUE>, keeping them in order.
+pub fn key_values<'a, T>(
+    data: &'a str,
+    separator: &str,
+    value: impl Fn(&mut Parser<'a>) -> PuzzleResult<T>,
+) -> PuzzleResult<Vec<(&'a str, T)>> {
+    Section {
+        first_line: 1,
+        text: data,
+    }
+    .parse_lines(|line| Parser::parse(line, |p| p.key_value(separator, &value)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_tokens() {
+        let (name, values) = Parser::parse("px{a=-12, m=+4,s=7}", |p| {
+            let name = p.word()?;
+            p.token("{")?;
+            let values = p.list(",", |p| p.key_value("=", Parser::int::<i32>))?;
+            p.token("}")?;
+
+            Ok((name, values))
+        })
+        .unwrap();
+
+        assert_eq!(name, "px");
+        assert_eq!(values, [("a", -12), ("m", 4), ("s", 7)]);
+
+        let mut parser = Parser::new("seeds: 79 14 x");
+        parser.token("seeds:").unwrap();
+        assert_eq!(parser.many(Parser::int::<u32>), [79, 14]);
+        assert_eq!(parser.one_of(&["<", "x"]).unwrap(), "x");
+        assert!(parser.is_done());
+
+        let mut parser = Parser::new("abc");
+        assert_eq!(parser.optional(|p| p.token("abd")), None);
+        assert_eq!(parser.take_while(|c| c != 'c'), "ab");
+        assert_eq!(parser.rest(), "c");
+    }
+
+    #[test]
+    fn errors_have_columns() {
+        let e = Parser::parse("a -> b,", |p| {
+            p.word()?;
+            p.token("->")?;
+            p.list(",", Parser::word)
+        })
+        .unwrap_err();
+
+        assert_eq!(e.column(), Some(8));
+        assert_eq!(e.text(), Some("a -> b,"));
+
+        let e = Parser::parse("1 2 3", |p| p.int::<u8>()).unwrap_err();
+        assert_eq!(e.column(), Some(3));
+
+        let e = Parser::parse("-3", |p| p.int::<u8>()).unwrap_err();
+        assert_eq!(e.to_string(), "column 1: Illegal number [-3] in [-3]");
+    }
+
+    #[test]
+    fn splits_sections() {
+        let data = "a\nb\n\n\nc\r\n\nd\n";
+        let sections = sections(data);
+
+        assert_eq!(sections.len(), 3);
+        assert_eq!(sections[0].text, "a\nb");
+        assert_eq!(sections[1].lines().collect::<Vec<_>>(), [(5, "c")]);
+        assert_eq!(sections[2].first_line, 7);
+
+        let e = sections[0]
+            .parse_lines(|line| Parser::parse(line, |p| p.int::<u8>()))
+            .unwrap_err();
+        assert_eq!((e.line(), e.column()), (Some(1), Some(1)));
+
+        let pairs = key_values("jqt: rhn xhk\nrsh: frs", ":", |p| Ok(p.many(Parser::word)));
+        assert_eq!(
+            pairs.unwrap(),
+            [("jqt", vec!["rhn", "xhk"]), ("rsh", vec!["frs"])]
+        );
+
+        let e = key_values("a = 1\nb 2", "=", Parser::int::<u8>).unwrap_err();
+        assert_eq!((e.line(), e.column()), (Some(2), Some(3)));
+    }
+}
@@ -0,0 +1,154 @@
+use crate::{gcd, Point};
+
+// A closed polygon on the integer lattice, given by its vertices in order around the edge. The last
+// vertex joins back up with the first. The edges shouldn't cross each other.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon {
+    vertices: Vec<Point<i64>>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Point<i64>>) -> Self {
+        Self { vertices }
+    }
+
+    pub fn vertices(&self) -> &[Point<i64>] {
+        &self.vertices
+    }
+
+    // Each edge as its two ends, including the one that closes the polygon.
+    pub fn edges(&self) -> impl Iterator<Item = (Point<i64>, Point<i64>)> + '_ {
+        let next = self.vertices.iter().cycle().skip(1);
+
+        self.vertices.iter().copied().zip(next.copied())
+    }
+
+    // Twice the signed area from the shoelace formula, which is always a whole number. It is positive
+    // when the vertices go counterclockwise as drawn, with rows growing downwards.
+    pub fn double_signed_area(&self) -> i64 {
+        self.edges()
+            .map(|(a, b)| a.row * b.col - a.col * b.row)
+            .sum()
+    }
+
+    pub fn signed_area(&self) -> f64 {
+        self.double_signed_area() as f64 / 2.0
+    }
+
+    // How many lattice points are on the edges.
+    pub fn boundary_points(&self) -> i64 {
+        self.edges()
+            .map(|(a, b)| gcd(b.row - a.row, b.col - a.col))
+            .sum()
+    }
+
+    // How many lattice points are strictly inside, from Pick's theorem: A = I + B / 2 - 1.
+    pub fn interior_points(&self) -> i64 {
+        if self.vertices.len() < 3 {
+            return 0;
+        }
+
+        (self.double_signed_area().abs() - self.boundary_points() + 2) / 2
+    }
+
+    pub fn on_boundary(&self, point: Point<i64>) -> bool {
+        self.edges().any(|(a, b)| {
+            let cross =
+                (b.row - a.row) * (point.col - a.col) - (b.col - a.col) * (point.row - a.row);
+
+            cross == 0
+                && point.row >= a.row.min(b.row)
+                && point.row <= a.row.max(b.row)
+                && point.col >= a.col.min(b.col)
+                && point.col <= a.col.max(b.col)
+        })
+    }
+
+    // Whether the point is strictly inside, by counting the edges a ray going right from it crosses.
+    pub fn contains(&self, point: Point<i64>) -> bool {
+        if self.on_boundary(point) {
+            return false;
+        }
+
+        let mut inside = false;
+
+        for (a, b) in self.edges() {
+            // Counting an edge when one end is above the point and the other isn't means a ray through
+            // a vertex is only counted once.
+            if (a.row > point.row) != (b.row > point.row) {
+                // Where the edge crosses the point's row, compared without dividing.
+                let offset = (point.row - a.row) * (b.col - a.col);
+                let span = b.row - a.row;
+
+                let crosses = if span > 0 {
+                    offset > (point.col - a.col) * span
+                } else {
+                    offset < (point.col - a.col) * span
+                };
+
+                if crosses {
+                    inside = !inside;
+                }
+            }
+        }
+
+        inside
+    }
+}
+
+impl FromIterator<Point<i64>> for Polygon {
+    fn from_iter<I: IntoIterator<Item = Point<i64>>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn polygon(vertices: &[(i64, i64)]) -> Polygon {
+        vertices.iter().map(|&vertex| Point::from(vertex)).collect()
+    }
+
+    #[test]
+    fn counts_lattice_points() {
+        // A 4x3 rectangle, going clockwise as drawn.
+        let rectangle = polygon(&[(0, 0), (0, 4), (3, 4), (3, 0)]);
+
+        assert_eq!(rectangle.double_signed_area(), -24);
+        assert_eq!(rectangle.signed_area(), -12.0);
+        assert_eq!(rectangle.boundary_points(), 14);
+        assert_eq!(rectangle.interior_points(), 6);
+
+        let reversed = polygon(&[(0, 0), (3, 0), (3, 4), (0, 4)]);
+        assert_eq!(reversed.double_signed_area(), 24);
+
+        // The diagonal edge from (0, 0) to (2, 4) passes through (1, 2).
+        let triangle = polygon(&[(0, 0), (2, 4), (2, 0)]);
+
+        assert_eq!(triangle.double_signed_area(), -8);
+        assert_eq!(triangle.boundary_points(), 8);
+        assert_eq!(triangle.interior_points(), 1);
+    }
+
+    #[test]
+    fn contains_points() {
+        // An L shape.
+        let shape = polygon(&[(0, 0), (0, 2), (2, 2), (2, 4), (4, 4), (4, 0)]);
+
+        assert!(shape.contains(Point::new(1, 1)));
+        assert!(shape.contains(Point::new(3, 3)));
+        assert!(!shape.contains(Point::new(1, 3)));
+        assert!(shape.contains(Point::new(2, 1)));
+        assert!(shape.on_boundary(Point::new(0, 1)));
+        assert!(!shape.contains(Point::new(0, 1)));
+        assert!(!shape.contains(Point::new(5, 1)));
+
+        let inside = (0..=4)
+            .flat_map(|row| (0..=4).map(move |col| Point::new(row, col)))
+            .filter(|&point| shape.contains(point))
+            .count() as i64;
+
+        assert_eq!(inside, shape.interior_points());
+    }
+}
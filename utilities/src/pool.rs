@@ -0,0 +1,98 @@
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+// Runs f on every task using up to `jobs` threads. Tasks are started in order, and on_done is called
+// on this thread with each result in task order, as soon as it and all of the tasks before it are
+// done.
+pub(crate) fn run_ordered<T, R, F, D>(tasks: &[T], jobs: usize, f: F, mut on_done: D)
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+    D: FnMut(usize, R),
+{
+    let next_task = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, tasks.len().max(1)) {
+            let sender = sender.clone();
+            let (next_task, f) = (&next_task, &f);
+
+            scope.spawn(move || loop {
+                let index = next_task.fetch_add(1, Ordering::Relaxed);
+
+                let Some(task) = tasks.get(index) else {
+                    break;
+                };
+
+                if sender.send((index, f(task))).is_err() {
+                    break;
+                }
+            });
+        }
+
+        drop(sender);
+
+        // Results that finished before some earlier task did.
+        let mut pending = BTreeMap::new();
+        let mut next_done = 0;
+
+        for (index, result) in receiver {
+            pending.insert(index, result);
+
+            while let Some(result) = pending.remove(&next_done) {
+                on_done(next_done, result);
+                next_done += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn reports_in_task_order() {
+        // The later tasks finish first.
+        let delays = [100, 75, 50, 25, 0];
+
+        for jobs in [1, 2, 5, 10] {
+            let finished = Mutex::new(vec![]);
+            let mut done = vec![];
+
+            run_ordered(
+                &delays,
+                jobs,
+                |&delay| {
+                    thread::sleep(Duration::from_millis(delay));
+                    finished.lock().unwrap().push(delay);
+
+                    delay * 2
+                },
+                |index, result| done.push((index, result)),
+            );
+
+            assert_eq!(done, [(0, 200), (1, 150), (2, 100), (3, 50), (4, 0)]);
+
+            if jobs == delays.len() {
+                assert_eq!(*finished.lock().unwrap(), [0, 25, 50, 75, 100]);
+            }
+        }
+    }
+
+    #[test]
+    fn runs_without_tasks() {
+        let mut done = 0;
+
+        run_ordered(&[] as &[u32], 0, |&task| task, |_, _| done += 1);
+
+        assert_eq!(done, 0);
+    }
+}
@@ -0,0 +1,422 @@
+use std::any::Any;
+use std::fs;
+use std::io::{self, Read};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::pool::run_ordered;
+use crate::{
+    benchmark, Answers, BenchStats, Part, PartResult, PuzzleError, PuzzleResult, RunOptions,
+    Solution,
+};
+
+// Worker threads get the same stack as the main thread, since some of the days recurse deeply.
+const WORKER_STACK_SIZE: usize = 8 * 1024 * 1024;
+
+fn instrument<F, T>(f: F, data: &str) -> (T, Duration)
+where
+    F: Fn(&str) -> T,
+{
+    let now = Instant::now();
+    let result = f(data);
+
+    (result, now.elapsed())
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    let message = payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown cause".to_string());
+
+    format!("Panicked: {message}")
+}
+
+// A day's puzzle file and answers, loaded once and shared by both parts.
+struct DayInput {
+    solution: Arc<dyn Solution>,
+    file_path: PathBuf,
+    contents: Arc<str>,
+    answers: Option<Answers>,
+}
+
+fn load_day(solution: &Arc<dyn Solution>, options: &RunOptions) -> PuzzleResult<DayInput> {
+    let day = solution.day();
+    let input = options
+        .input
+        .clone()
+        .unwrap_or_else(|| solution.default_input());
+
+    let (file_path, contents, answers) = match input.path(options.year, day) {
+        Some(file_path) => {
+            let contents =
+                fs::read_to_string(&file_path).map_err(|e| PuzzleError::io(&file_path, e))?;
+
+            let answers = if options.verify {
+                Some(Answers::load(&file_path)?)
+            } else {
+                None
+            };
+
+            (file_path, contents, answers)
+        }
+        // There are no recorded answers for stdin, so verifying gives UNKNOWN.
+        None => {
+            let file_path = PathBuf::from("-");
+
+            let mut contents = String::new();
+            io::stdin()
+                .read_to_string(&mut contents)
+                .map_err(|e| PuzzleError::io(&file_path, e))?;
+
+            (file_path, contents, options.verify.then(Answers::default))
+        }
+    };
+
+    Ok(DayInput {
+        solution: Arc::clone(solution),
+        file_path,
+        contents: Arc::from(contents),
+        answers,
+    })
+}
+
+// Runs one part on its own worker thread, waiting at most the timeout for it to finish. When
+// benchmarking, the timeout covers all of the runs. Threads can't be killed, so a part that times
+// out is left running in the background until it finishes or the program exits.
+fn run_on_worker(
+    input: &DayInput,
+    part: Part,
+    options: &RunOptions,
+) -> (PuzzleResult<String>, Duration, Option<BenchStats>) {
+    let name = format!("day {} part {part}", input.solution.day());
+    let solution = Arc::clone(&input.solution);
+    let data = Arc::clone(&input.contents);
+    let bench = options.bench.clone();
+
+    let (sender, receiver) = mpsc::channel();
+
+    let worker = move || {
+        // A panic is reported like any other error so that the remaining days still run.
+        let run_part = |data: &str| {
+            panic::catch_unwind(AssertUnwindSafe(|| match part {
+                Part::One => solution.part_1(data),
+                Part::Two => solution.part_2(data),
+            }))
+            .unwrap_or_else(|payload| Err(PuzzleError::solve(panic_message(payload))))
+        };
+
+        let result = match &bench {
+            Some(bench) => {
+                let (answer, stats) = benchmark(run_part, &data, bench);
+
+                (answer, stats.median, Some(stats))
+            }
+            None => {
+                let (answer, elapsed) = instrument(run_part, &data);
+
+                (answer, elapsed, None)
+            }
+        };
+
+        // Nobody is listening anymore if the part timed out.
+        let _ = sender.send(result);
+    };
+
+    let spawned = thread::Builder::new()
+        .name(name)
+        .stack_size(WORKER_STACK_SIZE)
+        .spawn(worker);
+
+    if let Err(e) = spawned {
+        let error = PuzzleError::solve(format!("Failed to start a worker: {e}"));
+
+        return (Err(error), Duration::ZERO, None);
+    }
+
+    let received = match options.timeout {
+        Some(timeout) => receiver.recv_timeout(timeout).map_err(|e| match e {
+            mpsc::RecvTimeoutError::Timeout => PuzzleError::timeout(timeout),
+            mpsc::RecvTimeoutError::Disconnected => worker_stopped(),
+        }),
+        None => receiver.recv().map_err(|_| worker_stopped()),
+    };
+
+    match received {
+        Ok(result) => result,
+        Err(e) => (Err(e), options.timeout.unwrap_or_default(), None),
+    }
+}
+
+fn worker_stopped() -> PuzzleError {
+    PuzzleError::solve("The worker stopped without an answer")
+}
+
+// Runs a part and checks its answer against the answers file when verifying.
+fn run_part(input: &DayInput, part: Part, options: &RunOptions) -> PartResult {
+    let day = input.solution.day();
+
+    let (answer, elapsed, stats) = run_on_worker(input, part, options);
+    let answer = answer.map_err(|e| e.in_day(day));
+
+    let verdict = match (&input.answers, &answer) {
+        (Some(answers), Ok(answer)) => Some(answers.check(part, answer)),
+        _ => None,
+    };
+
+    PartResult {
+        day,
+        part,
+        input: input.file_path.clone(),
+        answer,
+        elapsed,
+        verdict,
+        stats,
+    }
+}
+
+fn print_day(options: &RunOptions, day: usize, results: &[PartResult]) {
+    for result in results {
+        if let Err(e) = &result.answer {
+            eprintln!("{e}");
+        }
+    }
+
+    println!("{}", options.format.format_day(day, results));
+}
+
+pub struct RunSummary {
+    pub results: Vec<PartResult>,
+    // The days whose puzzle or answers file couldn't be loaded.
+    pub errors: Vec<PuzzleError>,
+    pub wall_time: Duration,
+}
+
+impl RunSummary {
+    pub fn failed(&self) -> bool {
+        !self.errors.is_empty() || self.results.iter().any(|result| result.failed())
+    }
+
+    // The time spent in the parts themselves, which is more than the wall time when running in
+    // parallel.
+    pub fn part_time(&self) -> Duration {
+        self.results.iter().map(|result| result.elapsed).sum()
+    }
+}
+
+// Runs the selected parts of each day, spread over options.jobs threads. Each part runs on a
+// worker thread so that a slow part can be given up on after the timeout. When verifying, each
+// answer is checked against the answers file next to the puzzle file. When benchmarking, the
+// reported time is the median.
+//
+// The results are printed in the requested format in day order, each day as soon as it and all of
+// the days before it are done. Part errors and days that couldn't be loaded go to stderr.
+pub fn run_puzzles(solutions: &[Arc<dyn Solution>], options: &RunOptions) -> RunSummary {
+    let start = Instant::now();
+
+    let days = solutions
+        .iter()
+        .map(|solution| load_day(solution, options).map_err(|e| e.in_day(solution.day())))
+        .collect::<Vec<PuzzleResult<DayInput>>>();
+
+    let parts = [Part::One, Part::Two]
+        .into_iter()
+        .filter(|&part| options.runs_part(part))
+        .collect::<Vec<Part>>();
+
+    // In day order, then part order, so that the first days finish first.
+    let tasks = days
+        .iter()
+        .enumerate()
+        .filter_map(|(index, day)| day.as_ref().ok().map(|input| (index, input)))
+        .flat_map(|(index, input)| parts.iter().map(move |&part| (index, input, part)))
+        .collect::<Vec<(usize, &DayInput, Part)>>();
+
+    let mut results = vec![];
+    let mut day_results = vec![];
+    let mut next_day = 0;
+
+    let print_errors = |days: &[PuzzleResult<DayInput>]| {
+        for day in days {
+            if let Err(e) = day {
+                eprintln!("{e}");
+            }
+        }
+    };
+
+    run_ordered(
+        &tasks,
+        options.jobs,
+        |&(_, input, part)| run_part(input, part, options),
+        |task, result| {
+            let (index, input, _) = tasks[task];
+
+            day_results.push(result);
+
+            if day_results.len() == parts.len() {
+                print_errors(&days[next_day..index]);
+                print_day(options, input.solution.day(), &day_results);
+
+                results.append(&mut day_results);
+                next_day = index + 1;
+            }
+        },
+    );
+
+    print_errors(&days[next_day..]);
+
+    RunSummary {
+        results,
+        errors: days.into_iter().filter_map(Result::err).collect(),
+        wall_time: start.elapsed(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+    use crate::{Days, InputFile, OutputFormat};
+
+    // Answers with its day after sleeping, or panics on day 13.
+    struct Stub {
+        day: usize,
+        delay: Duration,
+    }
+
+    impl Solution for Stub {
+        fn day(&self) -> usize {
+            self.day
+        }
+
+        fn title(&self) -> &'static str {
+            "Stub"
+        }
+
+        fn part_1(&self, data: &str) -> PuzzleResult<String> {
+            thread::sleep(self.delay);
+
+            if self.day == 13 {
+                panic!("Unlucky");
+            }
+
+            Ok(format!("{data}{}", self.day))
+        }
+
+        fn part_2(&self, _: &str) -> PuzzleResult<String> {
+            Ok(String::new())
+        }
+    }
+
+    fn stubs(days: &[(usize, u64)]) -> Vec<Arc<dyn Solution>> {
+        days.iter()
+            .map(|&(day, delay)| {
+                Arc::new(Stub {
+                    day,
+                    delay: Duration::from_millis(delay),
+                }) as Arc<dyn Solution>
+            })
+            .collect()
+    }
+
+    fn run(
+        name: &str,
+        days: &[(usize, u64)],
+        timeout: Option<Duration>,
+        jobs: usize,
+    ) -> RunSummary {
+        let path = env::temp_dir().join(format!("aoc-{name}-{}.txt", std::process::id()));
+        fs::write(&path, "day ").unwrap();
+
+        let options = RunOptions {
+            year: 2023,
+            days: Days::All,
+            part: Some(Part::One),
+            input: Some(InputFile::File(path.clone())),
+            verify: false,
+            bench: None,
+            format: OutputFormat::Text,
+            timeout,
+            jobs,
+        };
+
+        let summary = run_puzzles(&stubs(days), &options);
+
+        let _ = fs::remove_file(path);
+
+        summary
+    }
+
+    fn answers(summary: &RunSummary) -> Vec<(usize, String)> {
+        summary
+            .results
+            .iter()
+            .map(|result| match &result.answer {
+                Ok(answer) => (result.day, answer.clone()),
+                Err(e) => (result.day, e.to_string()),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn reports_results_in_day_order() {
+        // The later days finish first.
+        let summary = run("ordered", &[(1, 150), (2, 100), (3, 50), (4, 0)], None, 4);
+
+        assert!(!summary.failed());
+        assert_eq!(
+            answers(&summary),
+            [
+                (1, "day 1".to_string()),
+                (2, "day 2".to_string()),
+                (3, "day 3".to_string()),
+                (4, "day 4".to_string())
+            ]
+        );
+        assert!(summary.part_time() > summary.wall_time);
+    }
+
+    #[test]
+    fn reports_timeouts() {
+        let summary = run(
+            "timeout",
+            &[(1, 0), (2, 2000)],
+            Some(Duration::from_millis(100)),
+            2,
+        );
+
+        assert!(summary.failed());
+        assert_eq!(answers(&summary)[0], (1, "day 1".to_string()));
+
+        let timed_out = &summary.results[1];
+        assert!(timed_out
+            .answer
+            .as_ref()
+            .is_err_and(PuzzleError::is_timeout));
+        assert_eq!(timed_out.elapsed, Duration::from_millis(100));
+        assert_eq!(
+            OutputFormat::Text.format_day(2, &summary.results[1..]),
+            "[Day 2]: part 1: TIMEOUT"
+        );
+    }
+
+    #[test]
+    fn captures_panics() {
+        let summary = run("panic", &[(12, 0), (13, 0), (14, 0)], None, 1);
+
+        assert!(summary.failed());
+        assert_eq!(
+            answers(&summary),
+            [
+                (12, "day 12".to_string()),
+                (13, "Day 13: Panicked: Unlucky".to_string()),
+                (14, "day 14".to_string())
+            ]
+        );
+    }
+}
@@ -0,0 +1,579 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::{test_files_dir, year_dir};
+
+const MODULE_TEMPLATE: &str = "use utilities::{PuzzleError, PuzzleResult, Solution};
+
+pub struct DayN;
+
+impl Solution for DayN {
+    fn day(&self) -> usize {
+        N
+    }
+
+    fn title(&self) -> &'static str {
+        \"Day N\"
+    }
+
+    fn part_1(&self, data: &str) -> PuzzleResult<String> {
+        part_1(data).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, data: &str) -> PuzzleResult<String> {
+        part_2(data).map(|answer| answer.to_string())
+    }
+}
+
+fn part_1(_data: &str) -> PuzzleResult<usize> {
+    Err(PuzzleError::solve(\"Not solved yet\"))
+}
+
+fn part_2(_data: &str) -> PuzzleResult<usize> {
+    Err(PuzzleError::solve(\"Not solved yet\"))
+}
+
+#[cfg(test)]
+mod tests {}
+";
+
+const ANSWERS_TEMPLATE: &str = "# Uncomment once the answers are known.
+# part_1 =
+# part_2 =
+";
+
+const MANIFEST_TEMPLATE: &str = "[package]
+name = \"yearYYYY\"
+version = \"0.1.0\"
+edition = \"2021\"
+
+[dependencies]
+utilities = { path = \"../utilities\" }
+
+[build-dependencies]
+utilities = { path = \"../utilities\" }
+";
+
+const BUILD_TEMPLATE: &str = "fn main() {
+    utilities::generate_example_tests();
+}
+";
+
+const LIB_TEMPLATE: &str = "use utilities::Registry;
+
+#[cfg(test)]
+mod example_tests;
+
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+
+    registry
+}
+";
+
+const EXAMPLE_TESTS_TEMPLATE: &str = "use utilities::Part;
+
+use crate::registry;
+
+// The tests calling this are generated by build.rs. It's unused until a day has example answers.
+#[allow(dead_code)]
+fn check_example(day: usize, example: usize, part: Part) {
+    utilities::check_example(&registry(), day, example, part);
+}
+
+include!(concat!(env!(\"OUT_DIR\"), \"/example_tests.rs\"));
+";
+
+// Starts a new day in the year's crate under root: src/dayN.rs with a solution that isn't solved
+// yet, placeholder example and answers files in test_files/dayN/, and the mod line and registration
+// in src/lib.rs. Returns the files that were created or changed. Nothing is written if the day
+// already exists.
+//
+// The first day of a new year also starts the year's crate, see new_year.
+pub fn new_day(root: &Path, year: usize, day: usize) -> io::Result<Vec<PathBuf>> {
+    let crate_dir = root.join(year_dir(year));
+    let module_path = crate_dir.join(format!("src/day{day}.rs"));
+    let lib_path = crate_dir.join("src/lib.rs");
+    let test_dir = root.join(test_files_dir(year)).join(format!("day{day}"));
+
+    let mut changed = vec![];
+
+    if !crate_dir.exists() {
+        changed = new_year(root, year)?;
+    }
+
+    if module_path.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", module_path.display()),
+        ));
+    }
+
+    let lib = register_day(&fs::read_to_string(&lib_path)?, day).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "Can't find where to register the day in {}",
+                lib_path.display()
+            ),
+        )
+    })?;
+
+    let module = MODULE_TEMPLATE
+        .replace("DayN", &format!("Day{day}"))
+        .replace("Day N", &format!("Day {day}"))
+        .replace("        N\n", &format!("        {day}\n"));
+
+    fs::write(&module_path, module)?;
+    fs::write(&lib_path, lib)?;
+
+    fs::create_dir_all(&test_dir)?;
+
+    changed.push(module_path);
+
+    if !changed.contains(&lib_path) {
+        changed.push(lib_path);
+    }
+
+    // Keep anything that was already put in the test directory.
+    for (name, contents) in [
+        ("example.txt", ""),
+        ("example.answers", ANSWERS_TEMPLATE),
+        ("input.answers", ANSWERS_TEMPLATE),
+    ] {
+        let path = test_dir.join(name);
+
+        if !path.exists() {
+            fs::write(&path, contents)?;
+            changed.push(path);
+        }
+    }
+
+    Ok(changed)
+}
+
+// Starts the yearYYYY/ crate under root, with an empty registry and the build script that generates
+// the example tests, and wires it up: the crate joins the workspace members and the root crate's
+// dependencies in Cargo.toml, and its registry the match in src/main.rs. Returns the files that were
+// created or changed.
+fn new_year(root: &Path, year: usize) -> io::Result<Vec<PathBuf>> {
+    let crate_dir = root.join(year_dir(year));
+    let manifest_path = root.join("Cargo.toml");
+    let main_path = root.join("src/main.rs");
+
+    let not_found = |path: &Path, what: &str| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Can't find where to add the {what} in {}", path.display()),
+        )
+    };
+
+    // Work out both changes before writing anything.
+    let manifest = add_year_to_manifest(&fs::read_to_string(&manifest_path)?, year)
+        .ok_or_else(|| not_found(&manifest_path, "year's crate"))?;
+    let main = add_year_to_main(&fs::read_to_string(&main_path)?, year)
+        .ok_or_else(|| not_found(&main_path, "year's registry"))?;
+
+    fs::create_dir_all(crate_dir.join("src"))?;
+    fs::create_dir_all(root.join(test_files_dir(year)))?;
+
+    let mut changed = vec![];
+
+    for (name, contents) in [
+        (
+            "Cargo.toml",
+            MANIFEST_TEMPLATE.replace("YYYY", &year.to_string()),
+        ),
+        ("build.rs", BUILD_TEMPLATE.to_string()),
+        ("src/lib.rs", LIB_TEMPLATE.to_string()),
+        ("src/example_tests.rs", EXAMPLE_TESTS_TEMPLATE.to_string()),
+    ] {
+        let path = crate_dir.join(name);
+
+        fs::write(&path, contents)?;
+        changed.push(path);
+    }
+
+    fs::write(&manifest_path, manifest)?;
+    fs::write(&main_path, main)?;
+
+    changed.extend([manifest_path, main_path]);
+
+    Ok(changed)
+}
+
+// Adds the year's crate to the root crate's dependencies, among the other years, and to the
+// workspace members.
+fn add_year_to_manifest(manifest: &str, year: usize) -> Option<String> {
+    let mut lines = manifest
+        .lines()
+        .map(str::to_string)
+        .collect::<Vec<String>>();
+
+    let crate_name = format!("year{year}");
+    let dependency = format!("{crate_name} = {{ path = \"{crate_name}\" }}");
+
+    let year_of = |line: &str| {
+        line.strip_prefix("year")?
+            .split_once(" = ")?
+            .0
+            .parse::<usize>()
+            .ok()
+    };
+
+    let years = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, year_of(line)?)))
+        .collect::<Vec<(usize, usize)>>();
+
+    let dependency_index = match years.iter().find(|&&(_, other)| other > year) {
+        Some(&(i, _)) => i,
+        None => match years.last() {
+            Some(&(i, _)) => i + 1,
+            None => {
+                lines
+                    .iter()
+                    .position(|line| line.starts_with("utilities = "))?
+                    + 1
+            }
+        },
+    };
+
+    lines.insert(dependency_index, dependency);
+
+    // members = ["utilities", "year2015", ...], which sorts the years after utilities.
+    let members_line = lines
+        .iter_mut()
+        .find(|line| line.starts_with("members = ["))?;
+
+    let mut members = members_line
+        .strip_prefix("members = [")?
+        .strip_suffix(']')?
+        .split(',')
+        .map(|member| member.trim().to_string())
+        .filter(|member| !member.is_empty())
+        .collect::<Vec<String>>();
+
+    members.push(format!("\"{crate_name}\""));
+    members.sort();
+
+    *members_line = format!("members = [{}]", members.join(", "));
+
+    Some(lines.join("\n") + "\n")
+}
+
+// Adds "YYYY => yearYYYY::registry()," to the match in main.rs, among the other years.
+fn add_year_to_main(main: &str, year: usize) -> Option<String> {
+    let mut lines = main.lines().map(str::to_string).collect::<Vec<String>>();
+
+    let year_of = |line: &str| {
+        let (year, registry) = line.trim_start().split_once(" => ")?;
+
+        registry
+            .starts_with("year")
+            .then(|| year.parse::<usize>().ok())
+            .flatten()
+    };
+
+    let years = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, year_of(line)?)))
+        .collect::<Vec<(usize, usize)>>();
+
+    let &(last_index, _) = years.last()?;
+
+    let index = match years.iter().find(|&&(_, other)| other > year) {
+        Some(&(i, _)) => i,
+        None => last_index + 1,
+    };
+
+    let indent =
+        &lines[last_index][..lines[last_index].len() - lines[last_index].trim_start().len()];
+    let arm = format!("{indent}{year} => year{year}::registry(),");
+
+    lines.insert(index, arm);
+
+    Some(lines.join("\n") + "\n")
+}
+
+// Adds "mod dayN;" among the other day modules, which are sorted by name, and the registration
+// among the others, which are sorted by day.
+fn register_day(lib: &str, day: usize) -> Option<String> {
+    let mut lines = lib.lines().map(str::to_string).collect::<Vec<String>>();
+
+    let module = format!("day{day}");
+    let mod_line = format!("mod {module};");
+    let register_line = format!("    registry.register({module}::Day{day});");
+
+    let day_of = |line: &str, prefix: &str, suffix: &str| {
+        line.strip_prefix(prefix)?
+            .strip_suffix(suffix)?
+            .split("::")
+            .next()?
+            .strip_prefix("day")?
+            .parse::<usize>()
+            .ok()
+    };
+
+    let mods = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| day_of(line, "mod ", ";").is_some())
+        .map(|(i, line)| (i, line.clone()))
+        .collect::<Vec<(usize, String)>>();
+
+    // Sorted like rustfmt does, by the module name, which puts day2 before day20.
+    let mod_index = match mods
+        .iter()
+        .find(|(_, line)| line.trim_start_matches("mod ").trim_end_matches(';') > module.as_str())
+    {
+        Some(&(i, _)) => i,
+        None => match mods.last() {
+            Some(&(i, _)) => i + 1,
+            // The first day goes right before the example tests' module, or the registry.
+            None => lines.iter().position(|line| {
+                line.starts_with("#[cfg(test)]") || line.starts_with("pub fn registry")
+            })?,
+        },
+    };
+
+    if mods.is_empty() && lines[mod_index].starts_with("pub fn registry") {
+        lines.insert(mod_index, String::new());
+    }
+
+    lines.insert(mod_index, mod_line);
+
+    let registered = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, day_of(line, "    registry.register(", ");")?)))
+        .collect::<Vec<(usize, usize)>>();
+
+    let register_index = match registered.iter().find(|&&(_, other)| other > day) {
+        Some(&(i, _)) => i,
+        None => match registered.last() {
+            Some(&(i, _)) => i + 1,
+            None => {
+                lines
+                    .iter()
+                    .position(|line| line == "    let mut registry = Registry::new();")?
+                    + 2
+            }
+        },
+    };
+
+    lines.insert(register_index, register_line);
+
+    if registered.is_empty() {
+        lines.insert(register_index + 1, String::new());
+    }
+
+    Some(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    const LIB: &str = "mod day1;
+mod day10;
+mod day2;
+mod day20;
+
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+
+    registry.register(day1::Day1);
+    registry.register(day2::Day2);
+    registry.register(day10::Day10);
+
+    registry
+}
+";
+
+    #[test]
+    fn registers_in_order() {
+        let lib = register_day(LIB, 3).unwrap();
+
+        assert!(lib.starts_with("mod day1;\nmod day10;\nmod day2;\nmod day20;\nmod day3;\n\n"));
+        assert!(lib.contains(
+            "registry.register(day2::Day2);\n    registry.register(day3::Day3);\n    \
+             registry.register(day10::Day10);"
+        ));
+
+        let lib = register_day(LIB, 25).unwrap();
+
+        assert!(lib.starts_with("mod day1;\nmod day10;\nmod day2;\nmod day20;\nmod day25;\n\n"));
+    }
+
+    #[test]
+    fn adds_a_day() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+
+        fs::create_dir_all(root.join("year2023/src")).unwrap();
+        fs::write(root.join("year2023/src/lib.rs"), LIB).unwrap();
+
+        // Anything already in the test directory is kept.
+        fs::create_dir_all(root.join("year2023/test_files/day3")).unwrap();
+        fs::write(root.join("year2023/test_files/day3/example.txt"), "1 2 3").unwrap();
+
+        let changed = new_day(&root, 2023, 3).unwrap();
+        let relative = changed
+            .iter()
+            .map(|path| path.strip_prefix(&root).unwrap().to_str().unwrap())
+            .collect::<Vec<&str>>();
+
+        assert_eq!(
+            relative,
+            [
+                "year2023/src/day3.rs",
+                "year2023/src/lib.rs",
+                "year2023/test_files/day3/example.answers",
+                "year2023/test_files/day3/input.answers"
+            ]
+        );
+
+        let module = fs::read_to_string(root.join("year2023/src/day3.rs")).unwrap();
+        assert!(module.contains("pub struct Day3;"));
+        assert!(module.contains("        3\n"));
+        assert!(module.contains("\"Day 3\""));
+
+        let lib = fs::read_to_string(root.join("year2023/src/lib.rs")).unwrap();
+        assert_eq!(lib, register_day(LIB, 3).unwrap());
+        assert_eq!(
+            fs::read_to_string(root.join("year2023/test_files/day3/example.txt")).unwrap(),
+            "1 2 3"
+        );
+
+        let e = new_day(&root, 2023, 3).unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::AlreadyExists);
+        assert_eq!(
+            fs::read_to_string(root.join("year2023/src/lib.rs")).unwrap(),
+            lib
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn adds_a_year() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-year-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(root.join("Cargo.toml"), include_str!("../../Cargo.toml")).unwrap();
+        fs::write(root.join("src/main.rs"), include_str!("../../src/main.rs")).unwrap();
+
+        let changed = new_day(&root, 2016, 1).unwrap();
+        let relative = changed
+            .iter()
+            .map(|path| path.strip_prefix(&root).unwrap().to_str().unwrap())
+            .collect::<Vec<&str>>();
+
+        assert_eq!(
+            relative,
+            [
+                "year2016/Cargo.toml",
+                "year2016/build.rs",
+                "year2016/src/lib.rs",
+                "year2016/src/example_tests.rs",
+                "Cargo.toml",
+                "src/main.rs",
+                "year2016/src/day1.rs",
+                "year2016/test_files/day1/example.txt",
+                "year2016/test_files/day1/example.answers",
+                "year2016/test_files/day1/input.answers"
+            ]
+        );
+
+        let manifest = fs::read_to_string(root.join("year2016/Cargo.toml")).unwrap();
+        assert!(manifest.contains("name = \"year2016\""));
+
+        let lib = fs::read_to_string(root.join("year2016/src/lib.rs")).unwrap();
+        assert!(lib.contains("mod day1;\n#[cfg(test)]\nmod example_tests;\n"));
+        assert!(lib.contains("registry.register(day1::Day1);"));
+
+        let manifest = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        assert!(manifest.contains("year2016 = { path = \"year2016\" }"));
+
+        let main = fs::read_to_string(root.join("src/main.rs")).unwrap();
+        assert!(main.contains("2016 => year2016::registry(),"));
+
+        // The second day only touches the year's crate.
+        let changed = new_day(&root, 2016, 2).unwrap();
+        assert!(changed
+            .iter()
+            .all(|path| path.starts_with(root.join("year2016"))));
+        assert_eq!(
+            fs::read_to_string(root.join("Cargo.toml")).unwrap(),
+            manifest
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn adds_a_year_to_the_workspace() {
+        let manifest = add_year_to_manifest(include_str!("../../Cargo.toml"), 2016).unwrap();
+
+        assert!(manifest.contains(
+            "year2015 = { path = \"year2015\" }\nyear2016 = { path = \"year2016\" }\n\
+             year2023 = { path = \"year2023\" }\n"
+        ));
+        assert!(manifest
+            .contains("members = [\"utilities\", \"year2015\", \"year2016\", \"year2023\"]"));
+
+        let manifest = add_year_to_manifest(include_str!("../../Cargo.toml"), 2024).unwrap();
+
+        assert!(manifest
+            .contains("year2023 = { path = \"year2023\" }\nyear2024 = { path = \"year2024\" }\n"));
+        assert!(manifest.contains("\"year2023\", \"year2024\"]"));
+
+        let main = add_year_to_main(include_str!("../../src/main.rs"), 2016).unwrap();
+
+        assert!(main.contains(
+            "        2015 => year2015::registry(),\n        2016 => year2016::registry(),\n        \
+             2023 => year2023::registry(),\n"
+        ));
+
+        let main = add_year_to_main(include_str!("../../src/main.rs"), 2024).unwrap();
+
+        assert!(main.contains(
+            "        2023 => year2023::registry(),\n        2024 => year2024::registry(),\n"
+        ));
+    }
+
+    #[test]
+    fn registers_the_first_day() {
+        let lib = "use utilities::Registry;
+
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+
+    registry
+}
+";
+
+        assert_eq!(
+            register_day(lib, 1).unwrap(),
+            "use utilities::Registry;
+
+mod day1;
+
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+
+    registry.register(day1::Day1);
+
+    registry
+}
+"
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use std::sync::Arc;
+
+use crate::{InputFile, PuzzleResult};
+
+// A single day's puzzle. The parts return their answers already formatted so that days with
+// different answer types can live in the same registry, or an error if the input can't be solved.
+pub trait Solution: Send + Sync {
+    fn day(&self) -> usize;
+
+    fn title(&self) -> &'static str;
+
+    fn part_1(&self, data: &str) -> PuzzleResult<String>;
+
+    fn part_2(&self, data: &str) -> PuzzleResult<String>;
+
+    // The file to use when the command line doesn't ask for one. Days that are too slow on the
+    // real input can default to the example.
+    fn default_input(&self) -> InputFile {
+        InputFile::Input
+    }
+}
+
+// All of the registered days, kept sorted by day number. The solutions are shared so that they can
+// be handed to the worker threads that run the parts.
+#[derive(Default)]
+pub struct Registry {
+    solutions: Vec<Arc<dyn Solution>>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register<S: Solution + 'static>(&mut self, solution: S) {
+        let day = solution.day();
+
+        match self.solutions.binary_search_by_key(&day, |s| s.day()) {
+            Ok(_) => panic!("Day {day} registered twice!"),
+            Err(index) => self.solutions.insert(index, Arc::new(solution)),
+        }
+    }
+
+    pub fn get(&self, day: usize) -> Option<&dyn Solution> {
+        self.get_shared(day).map(|s| s.as_ref())
+    }
+
+    fn get_shared(&self, day: usize) -> Option<&Arc<dyn Solution>> {
+        self.solutions
+            .binary_search_by_key(&day, |s| s.day())
+            .ok()
+            .map(|index| &self.solutions[index])
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Solution> {
+        self.solutions.iter().map(|s| s.as_ref())
+    }
+
+    // Looks up each of the given days, returning the days that aren't registered as the error.
+    pub fn select(&self, days: &[usize]) -> Result<Vec<Arc<dyn Solution>>, Vec<usize>> {
+        let missing = days
+            .iter()
+            .filter(|&&day| self.get(day).is_none())
+            .copied()
+            .collect::<Vec<usize>>();
+
+        if !missing.is_empty() {
+            return Err(missing);
+        }
+
+        Ok(days
+            .iter()
+            .filter_map(|&day| self.get_shared(day).cloned())
+            .collect())
+    }
+
+    pub fn len(&self) -> usize {
+        self.solutions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.solutions.is_empty()
+    }
+}
@@ -0,0 +1,421 @@
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::fetch::{session_cookie, USER_AGENT};
+use crate::{test_files_dir, HttpClient, Part, SiteError, DEFAULT_BASE_URL, DEFAULT_YEAR};
+
+// What the site said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    // Wrong, without saying which way.
+    Incorrect,
+    // The site only accepts an answer every so often; this is how long is left.
+    Wait(Duration),
+    AlreadySolved,
+}
+
+impl Outcome {
+    // Finds the outcome in the article of the answer page.
+    pub fn parse(body: &str) -> Option<Self> {
+        if body.contains("That's the right answer") {
+            Some(Self::Correct)
+        } else if body.contains("your answer is too high") {
+            Some(Self::TooHigh)
+        } else if body.contains("your answer is too low") {
+            Some(Self::TooLow)
+        } else if body.contains("That's not the right answer") {
+            Some(Self::Incorrect)
+        } else if body.contains("You gave an answer too recently") {
+            Some(Self::Wait(parse_wait(body).unwrap_or_default()))
+        } else if body.contains("Did you already complete it") {
+            Some(Self::AlreadySolved)
+        } else {
+            None
+        }
+    }
+
+    // The name used in the history file. Only the outcomes that judge the answer are recorded.
+    fn key(&self) -> Option<&'static str> {
+        match self {
+            Self::Correct => Some("correct"),
+            Self::TooHigh => Some("too_high"),
+            Self::TooLow => Some("too_low"),
+            Self::Incorrect => Some("incorrect"),
+            Self::Wait(_) | Self::AlreadySolved => None,
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "correct" => Some(Self::Correct),
+            "too_high" => Some(Self::TooHigh),
+            "too_low" => Some(Self::TooLow),
+            "incorrect" => Some(Self::Incorrect),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Correct => write!(f, "Correct!"),
+            Self::TooHigh => write!(f, "Wrong, too high"),
+            Self::TooLow => write!(f, "Wrong, too low"),
+            Self::Incorrect => write!(f, "Wrong"),
+            Self::Wait(left) => write!(f, "Answered too recently, wait {left:?}"),
+            Self::AlreadySolved => write!(f, "Already solved"),
+        }
+    }
+}
+
+// Parses "You have 1m 5s left to wait." into 65 seconds.
+fn parse_wait(body: &str) -> Option<Duration> {
+    let start = body.find("You have ")? + "You have ".len();
+    let end = start + body[start..].find(" left to wait")?;
+
+    let mut secs = 0;
+
+    for piece in body[start..end].split_whitespace() {
+        let (number, unit) = piece.split_at(piece.find(|c: char| !c.is_ascii_digit())?);
+        let number = number.parse::<u64>().ok()?;
+
+        secs += match unit {
+            "h" => number * 3600,
+            "m" => number * 60,
+            "s" => number,
+            _ => return None,
+        };
+    }
+
+    Some(Duration::from_secs(secs))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    pub time: u64,
+    pub part: Part,
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+// Every judged answer for a day, stored in test_files/dayN/submissions.txt. Each line is
+// "<unix time> <part> <answer> <outcome>", e.g. "1702137600 2 4917124 too_low".
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct History {
+    pub attempts: Vec<Attempt>,
+}
+
+impl History {
+    pub fn parse(data: &str) -> Option<Self> {
+        let mut attempts = vec![];
+
+        for line in data.lines().filter(|line| !line.trim().is_empty()) {
+            let mut pieces = line.split_whitespace();
+
+            let time = pieces.next()?.parse().ok()?;
+            let part = match pieces.next()? {
+                "1" => Part::One,
+                "2" => Part::Two,
+                _ => return None,
+            };
+            let answer = pieces.next()?.to_string();
+            let outcome = Outcome::from_key(pieces.next()?)?;
+
+            attempts.push(Attempt {
+                time,
+                part,
+                answer,
+                outcome,
+            });
+        }
+
+        Some(Self { attempts })
+    }
+
+    pub fn load(path: &Path) -> Result<Self, SiteError> {
+        match fs::read_to_string(path) {
+            Ok(data) => Self::parse(&data).ok_or_else(|| {
+                SiteError::Read(
+                    path.to_path_buf(),
+                    io::Error::new(io::ErrorKind::InvalidData, "Malformed history"),
+                )
+            }),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(SiteError::Read(path.to_path_buf(), e)),
+        }
+    }
+
+    // Says why the answer shouldn't be submitted, if the history already settles it. Besides the
+    // exact answers that were wrong, numbers past a too high or too low answer are wrong too.
+    pub fn refusal(&self, part: Part, answer: &str) -> Option<String> {
+        let attempts = self.attempts.iter().filter(|attempt| attempt.part == part);
+        let number = answer.parse::<i128>().ok();
+
+        for attempt in attempts {
+            if attempt.outcome == Outcome::Correct {
+                return Some(format!(
+                    "Part {part} is already solved with {}",
+                    attempt.answer
+                ));
+            }
+
+            if attempt.answer == answer {
+                return Some(format!(
+                    "{answer} was already submitted: {}",
+                    attempt.outcome
+                ));
+            }
+
+            let bound = attempt.answer.parse::<i128>().ok();
+
+            let past_bound = match (number, bound, attempt.outcome) {
+                (Some(number), Some(bound), Outcome::TooHigh) => number > bound,
+                (Some(number), Some(bound), Outcome::TooLow) => number < bound,
+                _ => false,
+            };
+
+            if past_bound {
+                return Some(format!(
+                    "{answer} is wrong, {} was already {}",
+                    attempt.answer,
+                    attempt.outcome.key().unwrap().replace('_', " ")
+                ));
+            }
+        }
+
+        None
+    }
+
+    fn record(path: &Path, attempt: &Attempt) -> Result<(), SiteError> {
+        let write_error = |e| SiteError::Write(path.to_path_buf(), e);
+
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(write_error)?;
+
+        writeln!(
+            file,
+            "{} {} {} {}",
+            attempt.time,
+            attempt.part,
+            attempt.answer,
+            attempt.outcome.key().unwrap()
+        )
+        .map_err(write_error)
+    }
+}
+
+// Posts answers for a year to the site, keeping the history of each day next to its input under
+// history_dir.
+pub struct Submitter {
+    client: Box<dyn HttpClient>,
+    session: String,
+    pub year: usize,
+    pub base_url: String,
+    pub history_dir: PathBuf,
+}
+
+impl Submitter {
+    pub fn new(client: Box<dyn HttpClient>, session: String) -> Self {
+        Self {
+            client,
+            session,
+            year: DEFAULT_YEAR,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            history_dir: PathBuf::from("."),
+        }
+    }
+
+    pub fn history_path(&self, day: usize) -> PathBuf {
+        self.history_dir
+            .join(test_files_dir(self.year))
+            .join(format!("day{day}/submissions.txt"))
+    }
+
+    pub fn history(&self, day: usize) -> Result<History, SiteError> {
+        History::load(&self.history_path(day))
+    }
+
+    // Submits the answer unless the history shows that it would be wasted. Judged answers are
+    // added to the history.
+    pub fn submit(&self, day: usize, part: Part, answer: &str) -> Result<Outcome, SiteError> {
+        let answer = answer.trim();
+
+        if answer.is_empty() || answer.contains(char::is_whitespace) {
+            return Err(SiteError::Refused(format!("Illegal answer [{answer}]")));
+        }
+
+        if let Some(reason) = self.history(day)?.refusal(part, answer) {
+            return Err(SiteError::Refused(reason));
+        }
+
+        let url = format!(
+            "{}/{}/day/{day}/answer",
+            self.base_url.trim_end_matches('/'),
+            self.year
+        );
+        let form = format!("level={part}&answer={}", url_encode(answer));
+        let cookie = session_cookie(&self.session);
+        let headers = [("Cookie", cookie.as_str()), ("User-Agent", USER_AGENT)];
+
+        let response = self
+            .client
+            .post(&url, &headers, &form)
+            .map_err(SiteError::Request)?;
+
+        let outcome = match (response.status, Outcome::parse(&response.body)) {
+            (200, Some(outcome)) => outcome,
+            (400 | 500, _) if response.body.contains("log in") => {
+                return Err(SiteError::ExpiredSession)
+            }
+            (status, _) => return Err(SiteError::Http(status, response.body)),
+        };
+
+        if outcome.key().is_some() {
+            let time = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs();
+
+            let attempt = Attempt {
+                time,
+                part,
+                answer: answer.to_string(),
+                outcome,
+            };
+
+            let path = self.history_path(day);
+
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir).map_err(|e| SiteError::Write(dir.to_path_buf(), e))?;
+            }
+
+            History::record(&path, &attempt)?;
+        }
+
+        Ok(outcome)
+    }
+}
+
+fn url_encode(s: &str) -> String {
+    s.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{b:02X}"),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+    use crate::client_for;
+    use crate::fetch::tests::stub_server;
+
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high. \
+                            Please wait one minute before trying again.</p></article>";
+    const WAIT: &str = "<article><p>You gave an answer too recently; you have to wait after \
+                        submitting an answer before trying again.  You have 1m 5s left to \
+                        wait.</p></article>";
+
+    fn submitter(base_url: &str, name: &str) -> Submitter {
+        let history_dir = env::temp_dir().join(format!("aoc-submit-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&history_dir);
+
+        let mut submitter = Submitter::new(client_for(base_url), "token".to_string());
+        submitter.base_url = base_url.to_string();
+        submitter.history_dir = history_dir;
+
+        submitter
+    }
+
+    #[test]
+    fn parses_outcomes() {
+        assert_eq!(
+            Outcome::parse("<p>That's the right answer!  You are one gold star closer.</p>"),
+            Some(Outcome::Correct)
+        );
+        assert_eq!(Outcome::parse(TOO_HIGH), Some(Outcome::TooHigh));
+        assert_eq!(
+            Outcome::parse("That's not the right answer; your answer is too low."),
+            Some(Outcome::TooLow)
+        );
+        assert_eq!(
+            Outcome::parse("That's not the right answer.  If you're stuck, ..."),
+            Some(Outcome::Incorrect)
+        );
+        assert_eq!(
+            Outcome::parse(WAIT),
+            Some(Outcome::Wait(Duration::from_secs(65)))
+        );
+        assert_eq!(
+            Outcome::parse(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ),
+            Some(Outcome::AlreadySolved)
+        );
+        assert_eq!(Outcome::parse("<html>Something else</html>"), None);
+    }
+
+    #[test]
+    fn records_and_refuses_wrong_answers() {
+        let (base_url, requests) = stub_server(200, TOO_HIGH);
+        let submitter = submitter(&base_url, "wrong");
+
+        assert_eq!(
+            submitter.submit(5, Part::Two, "100").unwrap(),
+            Outcome::TooHigh
+        );
+
+        let history = submitter.history(5).unwrap();
+
+        assert_eq!(history.attempts.len(), 1);
+        assert_eq!(history.attempts[0].answer, "100");
+        assert_eq!(history.attempts[0].outcome, Outcome::TooHigh);
+
+        // Neither the same answer nor a bigger one gets sent.
+        assert!(matches!(
+            submitter.submit(5, Part::Two, "100"),
+            Err(SiteError::Refused(_))
+        ));
+        assert!(matches!(
+            submitter.submit(5, Part::Two, "150"),
+            Err(SiteError::Refused(_))
+        ));
+
+        // The other part has its own answers.
+        assert!(history.refusal(Part::One, "100").is_none());
+        assert!(history.refusal(Part::Two, "99").is_none());
+
+        let requests = requests.lock().unwrap();
+
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("POST /2023/day/5/answer HTTP/1.1\r\n"));
+        assert!(requests[0].ends_with("level=2&answer=100"));
+    }
+
+    #[test]
+    fn waiting_is_not_recorded() {
+        let (base_url, _) = stub_server(200, WAIT);
+        let submitter = submitter(&base_url, "wait");
+
+        assert_eq!(
+            submitter.submit(1, Part::One, "42").unwrap(),
+            Outcome::Wait(Duration::from_secs(65))
+        );
+        assert!(submitter.history(1).unwrap().attempts.is_empty());
+    }
+}
@@ -0,0 +1,55 @@
+use utilities::{PuzzleError, PuzzleResult, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    fn day(&self) -> usize {
+        1
+    }
+
+    fn title(&self) -> &'static str {
+        "Not Quite Lisp"
+    }
+
+    fn part_1(&self, data: &str) -> PuzzleResult<String> {
+        part_1(data).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, data: &str) -> PuzzleResult<String> {
+        part_2(data).map(|answer| answer.to_string())
+    }
+}
+
+// Each ( goes up a floor and each ) goes down one, find the floor Santa ends up on.
+fn part_1(data: &str) -> PuzzleResult<i32> {
+    let mut floor = 0;
+
+    for c in data.trim().chars() {
+        floor += step(c)?;
+    }
+
+    Ok(floor)
+}
+
+fn step(c: char) -> PuzzleResult<i32> {
+    match c {
+        '(' => Ok(1),
+        ')' => Ok(-1),
+        _ => Err(PuzzleError::parse(format!("Illegal character [{c}]"))),
+    }
+}
+
+// Find the position of the first character that takes Santa into the basement, starting at 1.
+fn part_2(data: &str) -> PuzzleResult<usize> {
+    let mut floor = 0;
+
+    for (i, c) in data.trim().chars().enumerate() {
+        floor += step(c)?;
+
+        if floor < 0 {
+            return Ok(i + 1);
+        }
+    }
+
+    Err(PuzzleError::solve("Never went into the basement"))
+}
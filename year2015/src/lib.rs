@@ -0,0 +1,13 @@
+use utilities::Registry;
+
+mod day1;
+#[cfg(test)]
+mod example_tests;
+
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+
+    registry.register(day1::Day1);
+
+    registry
+}
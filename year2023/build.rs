@@ -0,0 +1,3 @@
+fn main() {
+    utilities::generate_example_tests();
+}
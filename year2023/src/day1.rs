@@ -1,7 +1,23 @@
-use utilities;
+use utilities::{PuzzleResult, Solution};
 
-pub fn run() {
-    utilities::run_puzzle(1, true, part_1, part_2);
+pub struct Day1;
+
+impl Solution for Day1 {
+    fn day(&self) -> usize {
+        1
+    }
+
+    fn title(&self) -> &'static str {
+        "Trebuchet?!"
+    }
+
+    fn part_1(&self, data: &str) -> PuzzleResult<String> {
+        Ok(part_1(data).to_string())
+    }
+
+    fn part_2(&self, data: &str) -> PuzzleResult<String> {
+        Ok(part_2(data).to_string())
+    }
 }
 
 // Sum of first_digit_in_line * 10 + last_digit_in_line for each line
@@ -86,40 +102,34 @@ fn number_for_line2(line: &str) -> u32 {
         "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
     ];
 
-    let mut first_best = first_digit.clone();
-    let mut last_best = last_digit.clone();
+    let mut first_best = first_digit;
+    let mut last_best = last_digit;
 
     for (i, word) in words.iter().enumerate() {
-        match line.find(word) {
-            Some(position) => {
-                // Found the word, check if it's better than what we already have.
-                // first_best = match first_best {
-                //     None => Some((position, i + 1)),
-                //     Some(j) => {
+        if let Some(position) = line.find(word) {
+            // Found the word, check if it's better than what we already have.
+            // first_best = match first_best {
+            //     None => Some((position, i + 1)),
+            //     Some(j) => {
 
-                //     }
-                // }
+            //     }
+            // }
 
-                let potential_pair = (position, i as u32 + 1);
+            let potential_pair = (position, i as u32 + 1);
 
-                if first_best.is_none() || first_best.unwrap().0 > position {
-                    first_best = Some(potential_pair);
-                }
+            if first_best.is_none() || first_best.unwrap().0 > position {
+                first_best = Some(potential_pair);
             }
-            None => (),
         }
 
-        match line.rfind(word) {
-            Some(position) => {
-                let potential_pair = (position, i as u32 + 1);
+        if let Some(position) = line.rfind(word) {
+            let potential_pair = (position, i as u32 + 1);
 
-                last_best = match last_best {
-                    None => Some(potential_pair),
-                    Some(current_best) if current_best.0 < position => Some(potential_pair),
-                    Some(current_best) => Some(current_best),
-                };
-            }
-            None => (),
+            last_best = match last_best {
+                None => Some(potential_pair),
+                Some(current_best) if current_best.0 < position => Some(potential_pair),
+                Some(current_best) => Some(current_best),
+            };
         }
 
         // println!("    after processing {word}({i}), first_best {:?}, last_best {:?}", first_best, last_best);
@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+
+use utilities::{Direction, Grid, Point, Polygon, PuzzleError, PuzzleResult, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    fn day(&self) -> usize {
+        10
+    }
+
+    fn title(&self) -> &'static str {
+        "Pipe Maze"
+    }
+
+    fn part_1(&self, data: &str) -> PuzzleResult<String> {
+        part_1(data).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, data: &str) -> PuzzleResult<String> {
+        part_2(data).map(|answer| answer.to_string())
+    }
+}
+
+// Given the input, find the point along the loop farthest from where S is.
+fn part_1(data: &str) -> PuzzleResult<usize> {
+    let data = Grid::parse(data)?;
+
+    Ok(find_loop(&data)?.len() / 2)
+}
+
+// The tiles of the loop in order, starting from the S tile.
+fn find_loop(data: &Grid<char>) -> PuzzleResult<Vec<Point>> {
+    let animal = find_animal(data)?;
+    let tile_lookup = TileLookup::new();
+
+    let mut tiles = vec![animal];
+
+    // Search for the start of the loop
+    let (mut current, direction) = find_start(data, animal, &tile_lookup)?;
+    let mut direction = Some(direction);
+
+    // The direction runs out once the loop is back at the S tile.
+    while let Some(prev_direction) = direction {
+        direction = make_move(data, current, prev_direction, &tile_lookup)?;
+
+        if let Some(next_direction) = direction {
+            tiles.push(current);
+            current = move_point(data, current, next_direction)?;
+        }
+    }
+
+    Ok(tiles)
+}
+
+fn find_animal(data: &Grid<char>) -> PuzzleResult<Point> {
+    data.find(&'S')
+        .map(Point::from)
+        .ok_or_else(|| PuzzleError::parse("No S tile"))
+}
+
+fn move_point(data: &Grid<char>, point: Point, direction: Direction) -> PuzzleResult<Point> {
+    data.neighbor(point, direction)
+        .ok_or_else(|| PuzzleError::solve("The loop leaves the grid"))
+}
+
+struct TileLookup {
+    map: HashMap<char, (Direction, Direction)>,
+}
+
+impl TileLookup {
+    fn new() -> Self {
+        let mut map: HashMap<char, (Direction, Direction)> = HashMap::new();
+
+        // Store the directions allowed to move into this tile.
+        // When returning, need to flip direction to the outbound one.
+        map.insert('|', (Direction::Up, Direction::Down));
+        map.insert('-', (Direction::Right, Direction::Left));
+        map.insert('L', (Direction::Down, Direction::Left));
+        map.insert('J', (Direction::Down, Direction::Right));
+        map.insert('7', (Direction::Up, Direction::Right));
+        map.insert('F', (Direction::Up, Direction::Left));
+
+        Self { map }
+    }
+
+    fn allow_entry(&self, tile: char, prev_direction: Direction) -> bool {
+        self.map
+            .get(&tile)
+            .map(|&(a, b)| prev_direction == a || prev_direction == b)
+            .unwrap_or(false)
+    }
+
+    // The way out of the tile, if the tile is a pipe that can be entered going that way.
+    fn lookup_next_direction(&self, tile: char, prev_direction: Direction) -> Option<Direction> {
+        if !self.allow_entry(tile, prev_direction) {
+            return None;
+        }
+
+        let &(a, b) = self.map.get(&tile)?;
+        let opposite_entry = if prev_direction == a { b } else { a };
+
+        Some(opposite_entry.reverse())
+    }
+
+    // Whether the pipe next to the point in the direction connects to it.
+    fn connects(&self, data: &Grid<char>, point: Point, direction: Direction) -> bool {
+        data.neighbor(point, direction)
+            .is_some_and(|next| self.allow_entry(data[next], direction))
+    }
+}
+
+fn find_start(
+    data: &Grid<char>,
+    animal: Point,
+    lookup: &TileLookup,
+) -> PuzzleResult<(Point, Direction)> {
+    // 4 points to check: (r-1, c), (r, c+1), (r+1, c), (r, c-1)
+    let direction = Direction::ALL
+        .into_iter()
+        .find(|&direction| lookup.connects(data, animal, direction))
+        .ok_or_else(|| PuzzleError::solve("No pipe connects to the S tile"))?;
+
+    Ok((move_point(data, animal, direction)?, direction))
+}
+
+// The way out of the current tile, or None once back at the S tile.
+fn make_move(
+    data: &Grid<char>,
+    current: Point,
+    prev_direction: Direction,
+    lookup: &TileLookup,
+) -> PuzzleResult<Option<Direction>> {
+    let current_tile = data[current];
+
+    if current_tile == 'S' {
+        return Ok(None);
+    }
+
+    match lookup.lookup_next_direction(current_tile, prev_direction) {
+        Some(direction) => Ok(Some(direction)),
+        None => {
+            let line = data.row(current.row).iter().collect::<String>();
+
+            Err(
+                PuzzleError::parse(format!("The loop runs into [{current_tile}]"))
+                    .at_line(current.row + 1, &line)
+                    .at_column(current.col + 1),
+            )
+        }
+    }
+}
+
+// Figure out how many tiles are within the bounds of the loop. Taking the tiles as lattice points,
+// the loop is a polygon through them and the enclosed tiles are the points inside it.
+fn part_2(data: &str) -> PuzzleResult<usize> {
+    let data = Grid::parse(data)?;
+
+    let tiles = find_loop(&data)?;
+
+    let polygon = tiles
+        .into_iter()
+        .map(|tile| Point::new(tile.row as i64, tile.col as i64))
+        .collect::<Polygon>();
+
+    Ok(polygon.interior_points() as usize)
+}
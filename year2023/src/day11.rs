@@ -1,31 +1,35 @@
 use std::collections::HashMap;
 use std::collections::HashSet;
 
-use utilities;
+use utilities::{Grid, Point, PuzzleError, PuzzleResult, Solution};
 
-pub fn run() {
-    utilities::run_puzzle(11, true, part_1, part_2);
+pub struct Day11;
+
+impl Solution for Day11 {
+    fn day(&self) -> usize {
+        11
+    }
+
+    fn title(&self) -> &'static str {
+        "Cosmic Expansion"
+    }
+
+    fn part_1(&self, data: &str) -> PuzzleResult<String> {
+        part_1(data).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, data: &str) -> PuzzleResult<String> {
+        part_2(data).map(|answer| answer.to_string())
+    }
 }
 
 // Given a map of the galaxy containing . for empty space and # for galaxies, find the sum of all
 // of the distances between each pair of galaxies. Note that there is some space expansion, so any
 // rows and columns that don't have any galaxies double in width.
-fn part_1(data: &str) -> usize {
-    let space = Space::new(data, 2);
-
-    calculate_distances(&space)
-}
-
-#[derive(Debug)]
-struct Point {
-    row: usize,
-    col: usize,
-}
+fn part_1(data: &str) -> PuzzleResult<usize> {
+    let space = Space::new(data, 2)?;
 
-impl Point {
-    fn new(row: usize, col: usize) -> Self {
-        Self { row, col }
-    }
+    Ok(calculate_distances(&space))
 }
 
 #[derive(Debug)]
@@ -36,39 +40,32 @@ struct Space {
 }
 
 impl Space {
-    fn new(input: &str, expansion_factor: usize) -> Self {
-        let data = input
-            .lines()
-            .map(|line| line.chars().collect())
-            .collect::<Vec<Vec<char>>>();
+    fn new(input: &str, expansion_factor: usize) -> PuzzleResult<Self> {
+        // Whether each spot has a galaxy.
+        let grid = Grid::parse_with(input, |c| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(PuzzleError::parse(format!("Illegal space [{c}]"))),
+        })?;
 
-        let galaxies = Space::find_galaxies(&data);
+        let galaxies = Space::find_galaxies(&grid);
 
         let (row_offsets, col_offsets) =
-            Space::build_offset_tables(&galaxies, expansion_factor, data.len(), data[0].len());
+            Space::build_offset_tables(&galaxies, expansion_factor, grid.height(), grid.width());
 
-        Self {
+        Ok(Self {
             galaxies,
             row_offsets,
             col_offsets,
-        }
+        })
     }
 
-    fn find_galaxies(data: &Vec<Vec<char>>) -> HashMap<usize, Point> {
-        let mut galaxies = HashMap::new();
-
-        let mut galaxy_index = 0;
-
-        for (row, row_data) in data.iter().enumerate() {
-            for (col, cell) in row_data.iter().enumerate() {
-                if cell == &'#' {
-                    galaxies.insert(galaxy_index, Point::new(row, col));
-                    galaxy_index += 1;
-                }
-            }
-        }
-
-        galaxies
+    fn find_galaxies(grid: &Grid<bool>) -> HashMap<usize, Point> {
+        grid.iter()
+            .filter(|(_, &galaxy)| galaxy)
+            .enumerate()
+            .map(|(index, (position, _))| (index, Point::from(position)))
+            .collect()
     }
 
     fn build_offset_tables(
@@ -86,7 +83,7 @@ impl Space {
         let mut galaxy_rows = HashSet::new();
         let mut galaxy_cols = HashSet::new();
 
-        for (_, location) in galaxies {
+        for location in galaxies.values() {
             galaxy_rows.insert(location.row);
             galaxy_cols.insert(location.col);
         }
@@ -144,27 +141,23 @@ fn calculate_distances(space: &Space) -> usize {
 
     let mut distance_sum = 0;
 
-    // The pairs forms a triangle, half of n^2
+    // The pairs forms a triangle, half of n^2. With fewer than two galaxies there are no pairs.
     let galaxy_count = space.galaxy_count();
-    for first_index in 0..(galaxy_count - 1) {
+    for first_index in 0..galaxy_count {
         let first_point = galaxies.get(&first_index).unwrap();
 
         for second_index in (first_index + 1)..galaxy_count {
             let second_point = galaxies.get(&second_index).unwrap();
 
-            distance_sum += point_delta(first_point, second_point);
+            distance_sum += first_point.manhattan(*second_point);
         }
     }
 
     distance_sum
 }
 
-fn point_delta(a: &Point, b: &Point) -> usize {
-    (a.row.max(b.row) - a.row.min(b.row)) + (a.col.max(b.col) - a.col.min(b.col))
-}
-
-fn part_2(data: &str) -> usize {
-    let space = Space::new(data, 1000000);
+fn part_2(data: &str) -> PuzzleResult<usize> {
+    let space = Space::new(data, 1000000)?;
 
-    calculate_distances(&space)
+    Ok(calculate_distances(&space))
 }
@@ -0,0 +1,121 @@
+use utilities::{parse_lines, parse_number, Memo, PuzzleError, PuzzleResult, Solution};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    fn day(&self) -> usize {
+        12
+    }
+
+    fn title(&self) -> &'static str {
+        "Hot Springs"
+    }
+
+    fn part_1(&self, data: &str) -> PuzzleResult<String> {
+        part_1(data).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, data: &str) -> PuzzleResult<String> {
+        part_2(data).map(|answer| answer.to_string())
+    }
+}
+
+// Given a bunch of lines of data about springs, where each line contains the spring arrangement with
+// each spring either operation (.), damaged (#), or unknown (?), and information about the contiguous
+// groups of damaged springs, calculate the number of possible arrangements.
+fn part_1(data: &str) -> PuzzleResult<usize> {
+    Ok(parse_lines(data, |line| {
+        let (spring_info, damaged_info) = split_line(line)?;
+
+        process_spring_data_v2(spring_info, damaged_info)
+    })?
+    .iter()
+    .sum())
+}
+
+fn split_line(line: &str) -> PuzzleResult<(&str, &str)> {
+    line.split_once(' ')
+        .ok_or_else(|| PuzzleError::parse("Expected <SPRINGS> <GROUPS>"))
+}
+
+// The patterns are repeated 5 times each, with a '?' separating the spring parts, and a ',' separating the broken
+// spring runs.
+fn part_2(data: &str) -> PuzzleResult<usize> {
+    Ok(parse_lines(data, |line| {
+        let (spring_part, damaged_part) = split_line(line)?;
+
+        let mut spring_info = spring_part.to_owned();
+        let mut damaged_info = damaged_part.to_owned();
+
+        for _ in 0..4 {
+            spring_info.push('?');
+            spring_info.push_str(spring_part);
+            damaged_info.push(',');
+            damaged_info.push_str(damaged_part);
+        }
+
+        process_spring_data_v2(&spring_info, &damaged_info)
+    })?
+    .iter()
+    .sum())
+}
+
+fn process_spring_data_v2(spring_info: &str, damaged_info: &str) -> PuzzleResult<usize> {
+    if let Some(c) = spring_info.chars().find(|c| !"#.?".contains(*c)) {
+        return Err(PuzzleError::parse(format!("Illegal character [{c}]")));
+    }
+
+    let mut springs = ".".to_owned();
+    springs.push_str(spring_info);
+    springs.push('.');
+
+    let mut damaged = vec![false];
+
+    for s in damaged_info.split(",") {
+        let val = parse_number(s)?;
+
+        damaged.extend(std::iter::repeat_n(true, val));
+
+        damaged.push(false);
+    }
+
+    let mut memo = Memo::new();
+
+    Ok(count_arrangements(
+        &mut memo,
+        springs.as_bytes(),
+        &damaged,
+        0,
+        0,
+    ))
+}
+
+// The number of ways springs[i..] can match damaged[j..], where both have to run out together.
+fn count_arrangements(
+    memo: &mut Memo<(usize, usize), usize>,
+    springs: &[u8],
+    damaged: &[bool],
+    i: usize,
+    j: usize,
+) -> usize {
+    if i == springs.len() || j == damaged.len() {
+        return usize::from(i == springs.len() && j == damaged.len());
+    }
+
+    memo.get_or_compute((i, j), |memo| {
+        let (is_damaged, is_operational) = match springs[i] {
+            b'#' => (true, false),
+            b'.' => (false, true),
+            _ => (true, true),
+        };
+
+        if is_damaged && damaged[j] {
+            count_arrangements(memo, springs, damaged, i + 1, j + 1)
+        } else if is_operational && !damaged[j] {
+            count_arrangements(memo, springs, damaged, i + 1, j + 1)
+                + count_arrangements(memo, springs, damaged, i + 1, j)
+        } else {
+            0
+        }
+    })
+}
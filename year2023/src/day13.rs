@@ -0,0 +1,109 @@
+use utilities::{Grid, PuzzleError, PuzzleResult, Solution};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    fn day(&self) -> usize {
+        13
+    }
+
+    fn title(&self) -> &'static str {
+        "Point of Incidence"
+    }
+
+    fn part_1(&self, data: &str) -> PuzzleResult<String> {
+        part_1(data).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, data: &str) -> PuzzleResult<String> {
+        part_2(data).map(|answer| answer.to_string())
+    }
+}
+
+// For each pattern, find the column or row of reflection, and then sum the number of columns
+// before and 100 * the number of rows before.
+fn part_1(data: &str) -> PuzzleResult<usize> {
+    process_data(data, false)
+}
+
+fn process_data(data: &str, find_smudge: bool) -> PuzzleResult<usize> {
+    let mut sum = 0;
+
+    let mut pattern_lines = vec![];
+
+    // Errors point at the first line of the pattern.
+    let pattern_value = |pattern_lines: &[(usize, &str)]| {
+        let (start, first_line) = pattern_lines[0];
+        let lines = pattern_lines
+            .iter()
+            .map(|(_, line)| *line)
+            .collect::<Vec<&str>>();
+
+        calculate_pattern_value(&lines, find_smudge).map_err(|e| e.at_line(start + 1, first_line))
+    };
+
+    for (i, line) in data.lines().enumerate() {
+        if line.is_empty() {
+            if !pattern_lines.is_empty() {
+                sum += pattern_value(&pattern_lines)?;
+            }
+
+            pattern_lines.clear();
+
+            continue;
+        }
+
+        pattern_lines.push((i, line));
+    }
+
+    if !pattern_lines.is_empty() {
+        sum += pattern_value(&pattern_lines)?;
+    }
+
+    Ok(sum)
+}
+
+fn calculate_pattern_value(data: &[&str], find_smudge: bool) -> PuzzleResult<usize> {
+    let grid = Grid::from_rows(data.iter().map(|line| line.chars().collect()).collect())?;
+
+    // A line of reflection between rows wins over one between columns. The columns are checked as
+    // the rows of the transposed pattern.
+    let score = match find_reflection(&grid, find_smudge) {
+        Some(row) => 100 * (row + 1),
+        None => find_reflection(&grid.transpose(), find_smudge)
+            .map(|col| col + 1)
+            .ok_or_else(|| PuzzleError::solve("No line of reflection in the pattern"))?,
+    };
+
+    Ok(score)
+}
+
+// Finds the row after which the pattern is mirrored, with exactly one mark different when looking
+// for the smudge.
+fn find_reflection(grid: &Grid<char>, find_smudge: bool) -> Option<usize> {
+    let expected = if find_smudge { 1 } else { 0 };
+
+    (0..(grid.height() - 1)).find(|&row| count_differences(grid, row) == expected)
+}
+
+fn count_differences(grid: &Grid<char>, row: usize) -> usize {
+    let before_dist = row + 1;
+    let after_dist = grid.height() - row - 1;
+    let elements_to_match = before_dist.min(after_dist);
+
+    (0..elements_to_match)
+        .map(|i| {
+            grid.row(row - i)
+                .iter()
+                .zip(grid.row(row + 1 + i))
+                .filter(|(a, b)| a != b)
+                .count()
+        })
+        .sum()
+}
+
+// For each pattern, one of the marks is incorrect and swapping it leads to the line of symmetry
+// changing. Calculate the new score based on changing the one mark.
+fn part_2(data: &str) -> PuzzleResult<usize> {
+    process_data(data, true)
+}
@@ -1,9 +1,25 @@
 use std::collections::HashMap;
 
-use utilities;
+use utilities::{parse_number, PuzzleError, PuzzleResult, Solution};
 
-pub fn run() {
-    utilities::run_puzzle(15, true, part_1, part_2);
+pub struct Day15;
+
+impl Solution for Day15 {
+    fn day(&self) -> usize {
+        15
+    }
+
+    fn title(&self) -> &'static str {
+        "Lens Library"
+    }
+
+    fn part_1(&self, data: &str) -> PuzzleResult<String> {
+        Ok(part_1(data).to_string())
+    }
+
+    fn part_2(&self, data: &str) -> PuzzleResult<String> {
+        part_2(data).map(|answer| answer.to_string())
+    }
 }
 
 // Hash each string in the comma-separated list of tokens.
@@ -47,26 +63,28 @@ impl Lens {
 
 // Each token in the comma-separated input controls what happens to the numbered lens in the box at index
 // of the hash. Once all lenses are added, find the sum of the focal powers by (box_num * slot_num * lens_number)
-fn part_2(data: &str) -> usize {
+fn part_2(data: &str) -> PuzzleResult<usize> {
     let mut lens_map: LensMap = HashMap::new();
 
     for token in data.split(",") {
-        process_token(token, &mut lens_map);
+        process_token(token, &mut lens_map).map_err(|e| e.with_text(token))?;
     }
 
-    calculate_focal_power(&lens_map)
+    Ok(calculate_focal_power(&lens_map))
 }
 
-fn process_token(token: &str, lens_map: &mut LensMap) {
-    let add = token.contains('=');
-    let pieces = token.split(|c| c == '-' || c == '=').collect::<Vec<&str>>();
+fn process_token(token: &str, lens_map: &mut LensMap) -> PuzzleResult<()> {
+    let (label, lens_number) = if let Some((label, lens_number)) = token.split_once('=') {
+        (label, Some(parse_number::<usize>(lens_number)?))
+    } else if let Some(label) = token.strip_suffix('-') {
+        (label, None)
+    } else {
+        return Err(PuzzleError::parse("Expected <LABEL>=<LENS> or <LABEL>-"));
+    };
 
-    let label = pieces[0];
     let bucket_index = hash_str(label);
 
-    if add {
-        let lens_number = pieces[1].parse::<usize>().unwrap();
-
+    if let Some(lens_number) = lens_number {
         lens_map
             .entry(bucket_index)
             .and_modify(|lenses| {
@@ -104,6 +122,8 @@ fn process_token(token: &str, lens_map: &mut LensMap) {
             }
         });
     }
+
+    Ok(())
 }
 
 fn calculate_focal_power(lens_map: &LensMap) -> usize {
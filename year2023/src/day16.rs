@@ -0,0 +1,138 @@
+use utilities::{bfs, Direction, Grid, Point, PuzzleError, PuzzleResult, Solution};
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    fn day(&self) -> usize {
+        16
+    }
+
+    fn title(&self) -> &'static str {
+        "The Floor Will Be Lava"
+    }
+
+    fn part_1(&self, data: &str) -> PuzzleResult<String> {
+        part_1(data).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, data: &str) -> PuzzleResult<String> {
+        part_2(data).map(|answer| answer.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Empty,
+    // | and -
+    VerticalSplitter,
+    HorizontalSplitter,
+    // / and \
+    ForwardMirror,
+    BackMirror,
+}
+
+type TileGrid = Grid<Tile>;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Node {
+    position: Point,
+    direction: Direction,
+}
+
+impl Node {
+    fn new(row: usize, col: usize, direction: Direction) -> Self {
+        Self {
+            position: Point::new(row, col),
+            direction,
+        }
+    }
+}
+
+// Starting in the top left, the light beam moves right. Hitting splitters or mirrors causes it to change
+// directions and branch. In the end, calculate the number of tiles that are covered by a beam.
+fn part_1(data: &str) -> PuzzleResult<usize> {
+    let data = parse_grid(data)?;
+
+    Ok(process_beams(&data, Node::new(0, 0, Direction::Right)))
+}
+
+fn parse_grid(data: &str) -> PuzzleResult<TileGrid> {
+    TileGrid::parse_with(data, |c| match c {
+        '.' => Ok(Tile::Empty),
+        '|' => Ok(Tile::VerticalSplitter),
+        '-' => Ok(Tile::HorizontalSplitter),
+        '/' => Ok(Tile::ForwardMirror),
+        '\\' => Ok(Tile::BackMirror),
+        _ => Err(PuzzleError::parse(format!("Illegal tile [{c}]"))),
+    })
+}
+
+fn process_beams(data: &TileGrid, starting_node: Node) -> usize {
+    // Seems like I can do BFS with the stop conditions being hitting a node in the same "direction"
+    // (vertical or horizontal). The reason being that if a beam is travelling to the right, there
+    // shouldn't be a need to continue exploring if a beam was on the same row going left, because
+    // either it would reach a splitter and proceed in the other direction, or a mirror, in which case
+    // it will be the same pattern.
+    let beams = bfs([starting_node], |node| next_nodes(data, node), |_| false);
+
+    let mut covered = Grid::new(data.width(), data.height(), false);
+
+    for (node, _) in beams.iter() {
+        covered[node.position] = true;
+    }
+
+    covered.count(|&tile| tile)
+}
+
+fn next_nodes(data: &TileGrid, node: &Node) -> Vec<Node> {
+    // Depending on the tile, there may be a direction change or two directions.
+    let direction = node.direction;
+    let new_directions = match data[node.position] {
+        Tile::Empty => vec![direction],
+        // If moving along the splitter, just pass through. Otherwise split.
+        Tile::VerticalSplitter if direction.is_vertical() => vec![direction],
+        Tile::HorizontalSplitter if !direction.is_vertical() => vec![direction],
+        Tile::VerticalSplitter | Tile::HorizontalSplitter => {
+            vec![direction.turn_left(), direction.turn_right()]
+        }
+        // Mirror 90 degrees
+        Tile::ForwardMirror if direction.is_vertical() => vec![direction.turn_right()],
+        Tile::ForwardMirror => vec![direction.turn_left()],
+        Tile::BackMirror if direction.is_vertical() => vec![direction.turn_left()],
+        Tile::BackMirror => vec![direction.turn_right()],
+    };
+
+    new_directions
+        .into_iter()
+        .filter_map(|direction| {
+            data.neighbor(node.position, direction)
+                .map(|position| Node {
+                    position,
+                    direction,
+                })
+        })
+        .collect()
+}
+
+fn part_2(data: &str) -> PuzzleResult<usize> {
+    let data = parse_grid(data)?;
+
+    let mut max_count = 0;
+
+    // Check columns
+    for col in 0..data.width() {
+        let a = process_beams(&data, Node::new(0, col, Direction::Down));
+        let b = process_beams(&data, Node::new(data.height() - 1, col, Direction::Up));
+
+        max_count = max_count.max(a.max(b));
+    }
+
+    for row in 0..data.height() {
+        let a = process_beams(&data, Node::new(row, 0, Direction::Right));
+        let b = process_beams(&data, Node::new(row, data.width() - 1, Direction::Left));
+
+        max_count = max_count.max(a.max(b));
+    }
+
+    Ok(max_count)
+}
@@ -0,0 +1,163 @@
+use utilities::{dijkstra, Direction, Grid, Point, PuzzleError, PuzzleResult, Solution};
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    fn day(&self) -> usize {
+        17
+    }
+
+    fn title(&self) -> &'static str {
+        "Clumsy Crucible"
+    }
+
+    fn part_1(&self, data: &str) -> PuzzleResult<String> {
+        part_1(data).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, data: &str) -> PuzzleResult<String> {
+        part_2(data).map(|answer| answer.to_string())
+    }
+}
+
+// A crucible must move from the top left corner to the bottom right, with a max of 3 blocks in any given
+// direction at once. Each block has a value that is the amount of heat loss from that block. Find the path
+// that gives the minimum heat loss.
+
+// The direction is None at the start, before the crucible has moved.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+struct BlockDist {
+    direction: Option<Direction>,
+    steps: usize,
+}
+
+impl BlockDist {
+    fn start() -> Self {
+        Self {
+            steps: 0,
+            direction: None,
+        }
+    }
+
+    fn new(direction: Direction, steps: usize) -> Self {
+        Self {
+            direction: Some(direction),
+            steps,
+        }
+    }
+
+    fn advance(&self) -> Self {
+        Self {
+            steps: self.steps + 1,
+            ..*self
+        }
+    }
+}
+
+fn part_1(data: &str) -> PuzzleResult<usize> {
+    let grid = parse_input(data)?;
+
+    minimum_heat(&grid, 1, 3)
+}
+
+fn parse_input(data: &str) -> PuzzleResult<Grid<usize>> {
+    Grid::parse_with(data, |c| {
+        c.to_digit(10)
+            .map(|heat| heat as usize)
+            .ok_or_else(|| PuzzleError::parse(format!("Illegal heat loss [{c}]")))
+    })
+}
+
+fn minimum_heat(grid: &Grid<usize>, min_steps: usize, max_steps: usize) -> PuzzleResult<usize> {
+    let start_point = Point::default();
+    let end_point = Point::new(grid.height() - 1, grid.width() - 1);
+
+    // Each state is where the crucible is and how it got there.
+    let search = dijkstra(
+        [(start_point, BlockDist::start())],
+        |(position, block_dist)| {
+            get_neighbors(grid, position, block_dist, min_steps, max_steps)
+                .into_iter()
+                .map(|(neighbor, new_block_dist)| {
+                    let heat = get_heat_loss(grid, position, &neighbor);
+
+                    ((neighbor, new_block_dist), heat)
+                })
+                .collect::<Vec<_>>()
+        },
+        |(position, _)| *position == end_point,
+    );
+
+    search
+        .goal_cost()
+        .ok_or_else(|| PuzzleError::solve("No path reaches the bottom right"))
+}
+
+fn get_neighbors(
+    grid: &Grid<usize>,
+    point: &Point,
+    block_dist: &BlockDist,
+    min_steps: usize,
+    max_steps: usize,
+) -> Vec<(Point, BlockDist)> {
+    let mut neighbors = vec![];
+
+    for new_direction in Direction::ALL {
+        match block_dist.direction {
+            // The crucible can't reverse.
+            Some(direction) if new_direction == direction.reverse() => (),
+            // Going straight on is one block at a time, up to the max.
+            Some(direction) if new_direction == direction => {
+                if block_dist.steps < max_steps {
+                    if let Some(new_point) = grid.neighbor(*point, new_direction) {
+                        neighbors.push((new_point, block_dist.advance()));
+                    }
+                }
+            }
+            // Turning, or starting out, moves the min steps at once.
+            _ => {
+                if let Some(new_point) = point
+                    .step_by(new_direction, min_steps)
+                    .filter(|p| grid.contains(p.row, p.col))
+                {
+                    neighbors.push((new_point, BlockDist::new(new_direction, min_steps)));
+                }
+            }
+        }
+    }
+
+    neighbors
+}
+
+fn get_heat_loss(grid: &Grid<usize>, start: &Point, end: &Point) -> usize {
+    // get_neighbors only gives points that are in the grid.
+    assert!(start.row < grid.height());
+    assert!(start.col < grid.width());
+
+    assert!(end.row < grid.height());
+    assert!(end.col < grid.width());
+
+    let min_row = start.row.min(end.row);
+    let max_row = start.row.max(end.row);
+
+    let min_col = start.col.min(end.col);
+    let max_col = start.col.max(end.col);
+
+    let mut heat = 0;
+
+    for r in min_row..=max_row {
+        for c in min_col..=max_col {
+            heat += grid[(r, c)];
+        }
+    }
+
+    heat - grid[(start.row, start.col)]
+}
+
+// The crucibles have been upgraded, but now they can only move a minimum of 4 blocks in a direction, and a max
+// of 10.
+fn part_2(data: &str) -> PuzzleResult<usize> {
+    let grid = parse_input(data)?;
+
+    minimum_heat(&grid, 4, 10)
+}
@@ -0,0 +1,114 @@
+use utilities::{
+    parse_lines, parse_number, Direction, Point, Polygon, PuzzleError, PuzzleResult, Solution,
+};
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    fn day(&self) -> usize {
+        18
+    }
+
+    fn title(&self) -> &'static str {
+        "Lavaduct Lagoon"
+    }
+
+    fn part_1(&self, data: &str) -> PuzzleResult<String> {
+        part_1(data).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, data: &str) -> PuzzleResult<String> {
+        part_2(data).map(|answer| answer.to_string())
+    }
+}
+
+// Following the dig path, figure out the enclosed area. This seems like day 10 again.
+fn part_1(data: &str) -> PuzzleResult<usize> {
+    let dig_plan = parse_lines(data, |line| Dig::new(line, false))?;
+
+    calculate_area(&dig_plan)
+}
+
+// The fifth hex digit of the color gives the direction.
+fn parse_direction(s: &str) -> PuzzleResult<Direction> {
+    match s {
+        "0" => Ok(Direction::Right),
+        "1" => Ok(Direction::Down),
+        "2" => Ok(Direction::Left),
+        "3" => Ok(Direction::Up),
+        _ => Direction::parse(s),
+    }
+}
+
+#[derive(Debug)]
+struct Dig {
+    direction: Direction,
+    distance: usize,
+}
+
+impl Dig {
+    fn new(line: &str, use_color: bool) -> PuzzleResult<Self> {
+        let plan_pieces = line.split_ascii_whitespace().collect::<Vec<&str>>();
+
+        let [direction, distance, color] = plan_pieces[..] else {
+            return Err(PuzzleError::parse(
+                "Expected <DIRECTION> <DISTANCE> (#<COLOR>)",
+            ));
+        };
+
+        if use_color {
+            let color_piece = color
+                .strip_prefix("(#")
+                .and_then(|s| s.strip_suffix(")"))
+                .filter(|s| s.len() == 6 && s.is_ascii())
+                .ok_or_else(|| PuzzleError::parse(format!("Illegal color [{color}]")))?;
+
+            let distance = usize::from_str_radix(&color_piece[0..5], 16)
+                .map_err(|_| PuzzleError::parse(format!("Illegal color [{color}]")))?;
+
+            Ok(Self {
+                direction: parse_direction(&color_piece[5..])?,
+                distance,
+            })
+        } else {
+            Ok(Self {
+                direction: parse_direction(direction)?,
+                distance: parse_number(distance)?,
+            })
+        }
+    }
+}
+
+// The elves misinterpreted the input data, and instead the color field is the important information, where
+// the first 5 hex digits give the distance, and the last hex digit gives the direction.
+fn part_2(data: &str) -> PuzzleResult<usize> {
+    let dig_plan = parse_lines(data, |line| Dig::new(line, true))?;
+
+    calculate_area(&dig_plan)
+}
+
+// The trench runs through the middle of the dug out squares, so the area it encloses misses part of
+// the squares along the edge. Counting the squares as lattice points instead gives the whole lagoon,
+// as the points on the trench plus the points inside it.
+fn calculate_area(dig_plan: &[Dig]) -> PuzzleResult<usize> {
+    let mut current = Point::<i64>::default();
+    let mut vertices = vec![];
+
+    for dig in dig_plan {
+        vertices.push(current);
+
+        current = current
+            .step_by(dig.direction, dig.distance)
+            .ok_or_else(|| PuzzleError::solve("The dig plan goes too far"))?;
+    }
+
+    if current != Point::default() {
+        return Err(PuzzleError::solve(
+            "The dig plan doesn't end where it started",
+        ));
+    }
+
+    let lagoon = Polygon::new(vertices);
+
+    Ok((lagoon.boundary_points() + lagoon.interior_points()) as usize)
+}
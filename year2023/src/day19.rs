@@ -0,0 +1,355 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use utilities::{sections, Cuboid, Interval, Parser, PuzzleError, PuzzleResult, Section, Solution};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    fn day(&self) -> usize {
+        19
+    }
+
+    fn title(&self) -> &'static str {
+        "Aplenty"
+    }
+
+    fn part_1(&self, data: &str) -> PuzzleResult<String> {
+        part_1(data).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, data: &str) -> PuzzleResult<String> {
+        part_2(data).map(|answer| answer.to_string())
+    }
+}
+
+// The input contains instructions and part ratings, determine for each part if they are accepted or
+// rejected.
+fn part_1(data: &str) -> PuzzleResult<usize> {
+    let (instructions, part_ratings) = parse_input(data)?;
+
+    let parts = match part_ratings {
+        Some(part_ratings) => part_ratings.parse_lines(Part::new)?,
+        None => vec![],
+    };
+
+    let instruction_map = parse_instructions(&instructions)?;
+
+    parts
+        .iter()
+        .map(|part| process_part(part, &instruction_map))
+        .sum()
+}
+
+// Splits the input into the instructions and the part ratings, which come after a blank line.
+fn parse_input(data: &str) -> PuzzleResult<(Section<'_>, Option<Section<'_>>)> {
+    match sections(data)[..] {
+        [instructions] => Ok((instructions, None)),
+        [instructions, part_ratings] => Ok((instructions, Some(part_ratings))),
+        _ => Err(PuzzleError::parse(
+            "Expected the instructions and part ratings",
+        )),
+    }
+}
+
+#[derive(Debug)]
+enum Variable {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl Variable {
+    // Which axis of a RatingRange the variable is.
+    fn axis(&self) -> usize {
+        match self {
+            Self::X => 0,
+            Self::M => 1,
+            Self::A => 2,
+            Self::S => 3,
+        }
+    }
+
+    fn from_str(s: &str) -> PuzzleResult<Self> {
+        match s {
+            "x" => Ok(Self::X),
+            "m" => Ok(Self::M),
+            "a" => Ok(Self::A),
+            "s" => Ok(Self::S),
+            _ => Err(PuzzleError::parse(format!("Illegal rating [{s}]"))),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Part {
+    x: usize,
+    m: usize,
+    a: usize,
+    s: usize,
+}
+
+impl Part {
+    fn new(data: &str) -> PuzzleResult<Self> {
+        Parser::parse(data, |p| {
+            p.token("{")?;
+            let ratings = p.list(",", |p| p.key_value("=", Parser::int))?;
+            p.token("}")?;
+
+            match ratings[..] {
+                [("x", x), ("m", m), ("a", a), ("s", s)] => Ok(Self { x, m, a, s }),
+                _ => Err(p.error("Expected {x=<X>,m=<M>,a=<A>,s=<S>}")),
+            }
+        })
+    }
+
+    fn get_val(&self, var: &Variable) -> usize {
+        match var {
+            Variable::X => self.x,
+            Variable::M => self.m,
+            Variable::A => self.a,
+            Variable::S => self.s,
+        }
+    }
+
+    fn get_score(&self) -> usize {
+        self.x + self.m + self.a + self.s
+    }
+}
+
+#[derive(Debug)]
+struct Instruction {
+    var: Variable,
+    limit: usize,
+    lt: bool,
+    dest: String,
+    unconditional: bool,
+}
+
+impl Instruction {
+    // Either a condition and destination like "a<2006:qkq", or just a destination like "rfg".
+    fn parse(p: &mut Parser) -> PuzzleResult<Self> {
+        let condition = p.optional(|p| {
+            let var = Variable::from_str(p.one_of(&["x", "m", "a", "s"])?)?;
+            let lt = p.one_of(&["<", ">"])? == "<";
+            let limit = p.int()?;
+            p.token(":")?;
+
+            Ok((var, lt, limit))
+        });
+
+        let dest = p.word()?.to_owned();
+
+        Ok(match condition {
+            Some((var, lt, limit)) => Self {
+                var,
+                limit,
+                lt,
+                dest,
+                unconditional: false,
+            },
+            None => Self {
+                var: Variable::X,
+                limit: 0,
+                lt: false,
+                dest,
+                unconditional: true,
+            },
+        })
+    }
+
+    fn apply(&self, part: &Part) -> Option<&str> {
+        if self.unconditional {
+            return Some(&self.dest);
+        }
+
+        let part_val = part.get_val(&self.var);
+
+        if (self.lt && part_val < self.limit) || (!self.lt && part_val > self.limit) {
+            Some(&self.dest)
+        } else {
+            None
+        }
+    }
+}
+
+// The conditional instructions are tried in order, and the fallback is where a part goes when none
+// of them apply.
+#[derive(Debug)]
+struct InstructionNode {
+    instructions: Vec<Instruction>,
+    fallback: String,
+}
+
+impl InstructionNode {
+    // Parses <NAME>{<INSTRUCTIONS>}.
+    fn parse(line: &str) -> PuzzleResult<(&str, Self)> {
+        Parser::parse(line, |p| {
+            let name = p.word()?;
+            p.token("{")?;
+            let instructions = p.list(",", Instruction::parse)?;
+            p.token("}")?;
+
+            Ok((name, Self::new(instructions)?))
+        })
+    }
+
+    fn new(mut instructions: Vec<Instruction>) -> PuzzleResult<Self> {
+        // The last instruction has to catch everything, or apply can run out of instructions.
+        let fallback = match instructions.pop() {
+            Some(last) if last.unconditional => last.dest,
+            _ => return Err(PuzzleError::parse("Missing a final destination")),
+        };
+
+        Ok(Self {
+            instructions,
+            fallback,
+        })
+    }
+
+    fn apply(&self, part: &Part) -> &str {
+        self.instructions
+            .iter()
+            .find_map(|instruction| instruction.apply(part))
+            .unwrap_or(&self.fallback)
+    }
+
+    fn destinations(&self) -> impl Iterator<Item = &str> {
+        self.instructions
+            .iter()
+            .map(|instruction| instruction.dest.as_str())
+            .chain([self.fallback.as_str()])
+    }
+}
+
+fn parse_instructions(instructions: &Section) -> PuzzleResult<HashMap<String, InstructionNode>> {
+    let nodes = instructions.parse_lines(InstructionNode::parse)?;
+    let names = nodes.iter().map(|&(name, _)| name).collect::<HashSet<_>>();
+
+    // Every destination has to exist so that following the instructions can't get lost.
+    for ((i, instruction), (_, node)) in instructions.lines().zip(&nodes) {
+        for dest in node.destinations() {
+            if dest != "A" && dest != "R" && !names.contains(dest) {
+                return Err(PuzzleError::parse(format!("Unknown destination [{dest}]"))
+                    .at_line(i, instruction));
+            }
+        }
+    }
+
+    if !names.contains("in") {
+        return Err(PuzzleError::solve("Missing the in instructions"));
+    }
+
+    Ok(nodes
+        .into_iter()
+        .map(|(name, node)| (name.to_owned(), node))
+        .collect())
+}
+
+fn process_part(
+    part: &Part,
+    instruction_map: &HashMap<String, InstructionNode>,
+) -> PuzzleResult<usize> {
+    // Start at in, go until A or R. parse_instructions checked that every destination exists.
+    let mut current_instruction = "in";
+    let mut steps = 0;
+
+    while current_instruction != "A" && current_instruction != "R" {
+        // Without a loop, a part can't visit more instructions than there are.
+        if steps > instruction_map.len() {
+            return Err(PuzzleError::solve(format!(
+                "The instructions loop for the part {part:?}"
+            )));
+        }
+
+        current_instruction = instruction_map[current_instruction].apply(part);
+        steps += 1;
+    }
+
+    if current_instruction == "A" {
+        Ok(part.get_score())
+    } else {
+        Ok(0)
+    }
+}
+
+// Instead of sorting parts by the ratings, determine instead the number of combinations of ratings
+// that will yield an accepted part, assuming that each of the four ratings can be 1 to 4000.
+//
+// The example and input data appear to be DAGs and only branch; only the A and R nodes have more than
+// one other node pointing to them. This means that each node should just cut up the input range they
+// receive to send to each of their neighbors, so the ranges can be pushed through to figure out what
+// ranges reach the A node.
+fn part_2(data: &str) -> PuzzleResult<usize> {
+    let (instructions, _) = parse_input(data)?;
+
+    let instruction_map = parse_instructions(&instructions)?;
+
+    // Do a BFS through the graph to calculate the ranges for each node and what reaches the Accepted state.
+    let mut in_ranges: HashMap<&str, RatingRange> = HashMap::new();
+    let mut a_ranges = vec![];
+
+    let mut queue: VecDeque<&str> = VecDeque::new();
+    let mut visited_nodes: HashSet<&str> = HashSet::new();
+
+    let starting_node = "in";
+
+    queue.push_back(starting_node);
+    in_ranges.insert(starting_node, all_ratings());
+
+    while let Some(node) = queue.pop_front() {
+        if visited_nodes.contains(&node) {
+            continue;
+        }
+
+        let node_instructions = &instruction_map[node];
+
+        // Each instruction cuts the range that reached it in two: the part that goes to its neighbor, and
+        // the else part that carries on to the next instruction.
+        let mut else_range = in_ranges[node];
+
+        let mut send_range = |dest, range| {
+            if dest == "A" {
+                a_ranges.push((node, range));
+            } else if dest != "R" {
+                in_ranges.insert(dest, range);
+                queue.push_back(dest);
+            }
+        };
+
+        for instruction in node_instructions.instructions.iter() {
+            let axis = instruction.var.axis();
+
+            let dest_range = if instruction.unconditional {
+                else_range
+            } else if instruction.lt {
+                // var < limit goes to the neighbor, var >= limit carries on.
+                let (below, rest) = else_range.split_at(axis, instruction.limit);
+                else_range = rest;
+                below
+            } else {
+                // var > limit goes to the neighbor, var <= limit carries on.
+                let (below, rest) = else_range.split_at(axis, instruction.limit + 1);
+                else_range = below;
+                rest
+            };
+
+            send_range(&instruction.dest, dest_range);
+        }
+
+        // Whatever is left goes to the fallback.
+        send_range(&node_instructions.fallback, else_range);
+
+        visited_nodes.insert(node);
+    }
+
+    Ok(a_ranges.iter().map(|(_, range)| range.volume()).sum())
+}
+
+// The x, m, a and s ratings, in that order.
+type RatingRange = Cuboid<usize, 4>;
+
+// Each of the ratings can be 1 to 4000.
+fn all_ratings() -> RatingRange {
+    Cuboid::new([Interval::inclusive(1, 4000); 4])
+}
@@ -0,0 +1,90 @@
+use utilities::{parse_lines, parse_number, PuzzleError, PuzzleResult, Solution};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    fn day(&self) -> usize {
+        2
+    }
+
+    fn title(&self) -> &'static str {
+        "Cube Conundrum"
+    }
+
+    fn part_1(&self, data: &str) -> PuzzleResult<String> {
+        part_1(data).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, data: &str) -> PuzzleResult<String> {
+        part_2(data).map(|answer| answer.to_string())
+    }
+}
+
+fn part_1(data: &str) -> PuzzleResult<u32> {
+    calc_score(data, true)
+}
+
+fn calc_score(data: &str, as_sum: bool) -> PuzzleResult<u32> {
+    Ok(parse_lines(data, |line| line_score(line, as_sum))?
+        .iter()
+        .sum())
+}
+
+fn line_score(line: &str, as_sum: bool) -> PuzzleResult<u32> {
+    // line: Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+
+    let (game, sub_games) = line
+        .split_once(':')
+        .ok_or_else(|| PuzzleError::parse("Missing ':'"))?;
+    // game: "Game 1", sub_games: "3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green"
+
+    let num: u32 = match game.split_once(' ') {
+        Some(("Game", num)) => parse_number(num)?,
+        _ => return Err(PuzzleError::parse(format!("Illegal game [{game}]"))),
+    };
+
+    let mut valid = true;
+
+    let mut r_count: u32 = 0;
+    let mut g_count: u32 = 0;
+    let mut b_count: u32 = 0;
+
+    for sub_game in sub_games.split(";") {
+        // sub_game: "3 blue, 4 red"
+        for colors in sub_game.split(",") {
+            // colors: "3 blue"
+            let (count, color) = colors
+                .trim()
+                .split_once(" ")
+                .ok_or_else(|| PuzzleError::parse(format!("Illegal cubes [{colors}]")))?;
+
+            let count: u32 = parse_number(count)?;
+
+            match color {
+                "red" => {
+                    valid = valid && count <= 12;
+                    r_count = r_count.max(count);
+                }
+                "green" => {
+                    valid = valid && count <= 13;
+                    g_count = g_count.max(count);
+                }
+                "blue" => {
+                    valid = valid && count <= 14;
+                    b_count = b_count.max(count);
+                }
+                _ => return Err(PuzzleError::parse(format!("Unknown color [{color}]"))),
+            }
+        }
+    }
+
+    if as_sum {
+        Ok(if valid { num } else { 0 })
+    } else {
+        Ok(r_count * g_count * b_count)
+    }
+}
+
+fn part_2(data: &str) -> PuzzleResult<u32> {
+    calc_score(data, false)
+}
@@ -1,19 +1,36 @@
 use std::collections::{HashMap, HashSet, VecDeque};
+use std::ops::Add;
 
-use utilities;
+use utilities::{accumulate_at, crt, first_in_class, Parser, PuzzleError, PuzzleResult, Solution};
 
-pub fn run() {
-    utilities::run_puzzle(20, true, part_1, part_2);
+pub struct Day20;
+
+impl Solution for Day20 {
+    fn day(&self) -> usize {
+        20
+    }
+
+    fn title(&self) -> &'static str {
+        "Pulse Propagation"
+    }
+
+    fn part_1(&self, data: &str) -> PuzzleResult<String> {
+        part_1(data).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, data: &str) -> PuzzleResult<String> {
+        part_2(data).map(|answer| answer.to_string())
+    }
 }
 
 // Given an arrangement of connected modules, calculate the product of low and high pulses that are sent
 // after pushing the start button 1000 times.
-fn part_1(data: &str) -> usize {
+fn part_1(data: &str) -> PuzzleResult<usize> {
     const PUSH_BUTTON_COUNT: usize = 1000;
 
-    let mut modules = parse_modules(data);
+    let mut modules = parse_modules(data)?;
 
-    count_pulses(&mut modules, PUSH_BUTTON_COUNT)
+    Ok(count_pulses(&mut modules, PUSH_BUTTON_COUNT))
 }
 
 // I think this can be implemented by holding all of the modules, and then keeping a queue of
@@ -259,36 +276,51 @@ enum Module {
 
 type Modules = HashMap<String, Module>;
 
-fn parse_modules(data: &str) -> Modules {
+fn parse_modules(data: &str) -> PuzzleResult<Modules> {
     let mut modules = HashMap::new();
 
     let mut conjunction_modules: HashMap<String, Vec<String>> = HashMap::new();
 
-    for line in data.lines() {
-        let pieces = line
-            .split(|c: char| c.is_ascii_whitespace() || c == ',')
-            .filter(|&s| !s.is_empty() && s != "->")
-            .collect::<Vec<&str>>();
-
-        let name = pieces[0];
+    for (i, line) in data.lines().enumerate() {
+        let (kind, name, destinations) = Parser::parse(line, |p| {
+            let kind = p.optional(|p| p.one_of(&["%", "&"]));
+            let name = p.word()?;
+            p.token("->")?;
+
+            Ok((kind, name, p.list(",", Parser::word)?))
+        })
+        .map_err(|e| e.at_line(i + 1, line))?;
+
+        // The button is added below, and only ever sends pulses.
+        let error = if name == "button" || destinations.contains(&"button") {
+            Some("The button can't be wired up".to_string())
+        } else if modules.contains_key(name) {
+            Some(format!("Module [{name}] is defined twice"))
+        } else {
+            None
+        };
 
-        let (module_name, module) = match &name[..1] {
-            "b" => (name, Module::Broadcast(Broadcast::new(&name, &pieces[1..]))),
-            "%" => (
-                &name[1..],
-                Module::FlipFlop(FlipFlop::new(&name[1..], &pieces[1..])),
-            ),
-            "&" => {
-                let module_name = &name[1..];
+        if let Some(error) = error {
+            return Err(PuzzleError::parse(error).at_line(i + 1, line));
+        }
 
-                conjunction_modules.insert(module_name.to_string(), vec![]);
+        let (module_name, module) = match kind {
+            None if name == "broadcaster" => {
+                (name, Module::Broadcast(Broadcast::new(name, &destinations)))
+            }
+            Some("%") => (name, Module::FlipFlop(FlipFlop::new(name, &destinations))),
+            Some(_) => {
+                conjunction_modules.insert(name.to_string(), vec![]);
 
                 (
-                    module_name,
-                    Module::Conjunction(Conjunction::new(module_name, &pieces[1..])),
+                    name,
+                    Module::Conjunction(Conjunction::new(name, &destinations)),
                 )
             }
-            _ => panic!("Illegal module name"),
+            None => {
+                return Err(PuzzleError::parse(format!("Illegal module name [{name}]"))
+                    .at_line(i + 1, line))
+            }
         };
 
         modules.insert(module_name.to_string(), module);
@@ -321,76 +353,41 @@ fn parse_modules(data: &str) -> Modules {
         });
     }
 
-    modules
+    Ok(modules)
 }
 
-fn count_pulses(modules: &mut Modules, push_button_count: usize) -> usize {
-    let (_, _, initial_state) = gather_state(modules);
-
-    let mut state_map = HashMap::new();
-
-    state_map.insert(initial_state, (0, 0, 0));
-
-    let mut cycle_count = None;
-    let mut cycle_length = None;
-
-    for i in 0..push_button_count {
-        let push_count = i + 1;
-
-        let (low, high, state) = push_once(modules);
-
-        if state_map.contains_key(&state) {
-            let (original_push_count, _, _) = state_map.get(&state).unwrap();
+// The low and high pulses sent.
+#[derive(Debug, Clone, Copy, Default)]
+struct PulseCount {
+    low: usize,
+    high: usize,
+}
 
-            cycle_count = Some((low, high));
-            cycle_length = Some(push_count - original_push_count);
+impl Add for PulseCount {
+    type Output = Self;
 
-            break;
+    fn add(self, other: Self) -> Self {
+        Self {
+            low: self.low + other.low,
+            high: self.high + other.high,
         }
-
-        state_map.insert(state, (push_count, low, high));
     }
+}
 
-    // If there was a cycle, use that to extrapolate the final result.
-    let (total_low, total_high) = if cycle_count.is_some() {
-        let (cycle_low, cycle_high) = cycle_count.unwrap();
-        let cycle_length = cycle_length.unwrap();
-
-        let full_cycles = push_button_count / cycle_length;
-        let rem = push_button_count % cycle_length;
-
-        let mut rem_count = None;
-
-        for (_, (push_count, low, high)) in state_map {
-            if push_count == rem {
-                rem_count = Some((low, high));
-                break;
-            }
-        }
-
-        let (rem_low, rem_high) = rem_count.unwrap();
-
-        let total_low = cycle_low * full_cycles + rem_low;
-        let total_high = cycle_high * full_cycles + rem_high;
-
-        (total_low, total_high)
-    } else {
-        let mut total = None;
-
-        for (_, (push_count, low, high)) in state_map {
-            if push_count == push_button_count {
-                total = Some((low, high));
-                break;
-            }
-        }
+fn count_pulses(modules: &mut Modules, push_button_count: usize) -> usize {
+    let (_, _, initial_state) = gather_state(modules);
 
-        total.unwrap()
-    };
+    // The state string covers everything that affects the next push, so the modules can be pushed
+    // alongside it.
+    let total = accumulate_at(initial_state, |_| push_once(modules), push_button_count);
 
-    total_low * total_high
+    total.low * total.high
 }
 
-fn push_once(modules: &mut Modules) -> (usize, usize, String) {
+// Pushes the button, returning the state of the modules afterwards and the pulses that were sent.
+fn push_once(modules: &mut Modules) -> (String, PulseCount) {
+    let (low_before, high_before, _) = gather_state(modules);
+
     let mut queue: VecDeque<Pulse> = VecDeque::new();
 
     if let Some(Module::Button(button)) = modules.get_mut("button") {
@@ -399,22 +396,30 @@ fn push_once(modules: &mut Modules) -> (usize, usize, String) {
 
     while let Some(pulse) = queue.pop_front() {
         // Apply the pulse to the destination module, which can generate more pulses
-        if let Some(dest_module) = modules.get_mut(&pulse.end) {
-            let new_pulses = match dest_module {
-                Module::Broadcast(broadcast) => broadcast.apply(&pulse),
-                Module::FlipFlop(flipflop) => flipflop.apply(&pulse),
-                Module::Conjunction(conjunction) => conjunction.apply(&pulse),
-                _ => panic!("Illegal destination for pulse!"),
-            };
-
-            // Add the new pulses to the queue.
-            new_pulses
-                .into_iter()
-                .for_each(|pulse| queue.push_back(pulse));
-        }
+        queue.extend(apply_pulse(modules, &pulse));
     }
 
-    gather_state(modules)
+    let (low, high, state) = gather_state(modules);
+
+    (
+        state,
+        PulseCount {
+            low: low - low_before,
+            high: high - high_before,
+        },
+    )
+}
+
+// The pulses the destination module sends on. Pulses to modules that don't exist, like rx, go
+// nowhere.
+fn apply_pulse(modules: &mut Modules, pulse: &Pulse) -> Vec<Pulse> {
+    match modules.get_mut(&pulse.end) {
+        Some(Module::Broadcast(broadcast)) => broadcast.apply(pulse),
+        Some(Module::FlipFlop(flipflop)) => flipflop.apply(pulse),
+        Some(Module::Conjunction(conjunction)) => conjunction.apply(pulse),
+        // parse_modules doesn't let anything send to the button.
+        Some(Module::Button(_)) | None => vec![],
+    }
 }
 
 fn gather_state(modules: &Modules) -> (usize, usize, String) {
@@ -442,34 +447,54 @@ fn gather_state(modules: &Modules) -> (usize, usize, String) {
 }
 
 // Determine how many button pushes is required to receive a low pulse at rx.
-fn part_2(data: &str) -> usize {
-    let mut modules = parse_modules(data);
+fn part_2(data: &str) -> PuzzleResult<usize> {
+    let mut modules = parse_modules(data)?;
 
     // Analysis of the input showed that there are 4 separate chains that come together to produce the final
     // result at rx, so find the cycle of each one.
-    let stop_modules = vec!["xm", "tr", "dr", "nh"];
+    let stop_modules = ["xm", "tr", "dr", "nh"];
+
+    // Without all of them the loop below would never finish, which is the case for the examples.
+    if let Some(missing) = stop_modules.iter().find(|&&s| !modules.contains_key(s)) {
+        return Err(PuzzleError::solve(format!(
+            "Missing module [{missing}], this only works on the real input"
+        )));
+    }
+
     let stop_modules = stop_modules.iter().fold(HashSet::new(), |mut acc, s| {
         acc.insert(s.to_string());
-        return acc;
+        acc
     });
 
-    let mut found_stop_modules = HashMap::new();
+    // The first two pushes that reach each stop module give the start and length of its cycle.
+    let mut found_stop_modules: HashMap<String, Vec<usize>> = HashMap::new();
 
     let mut push_count: usize = 1;
-    while found_stop_modules.len() != stop_modules.len() {
+    while found_stop_modules.len() != stop_modules.len()
+        || found_stop_modules.values().any(|pushes| pushes.len() < 2)
+    {
         if let Some(stop_module) = push_once_with_stop(&mut modules, &stop_modules) {
-            found_stop_modules.insert(stop_module, push_count);
+            let pushes = found_stop_modules.entry(stop_module).or_default();
+
+            if pushes.len() < 2 {
+                pushes.push(push_count);
+            }
         }
 
         push_count += 1;
     }
 
-    let mut cycle_lcm = 1;
-    for cycle_len in found_stop_modules.values() {
-        cycle_lcm = lcm(cycle_lcm, *cycle_len);
-    }
+    let cycles: Vec<(i128, i128)> = found_stop_modules
+        .values()
+        .map(|pushes| (pushes[0] as i128, (pushes[1] - pushes[0]) as i128))
+        .collect();
 
-    cycle_lcm
+    let (first, period) =
+        crt(&cycles).ok_or_else(|| PuzzleError::solve("The stop module cycles never line up"))?;
+
+    // The first push that's on every cycle once they have all started.
+    let earliest = cycles.iter().map(|&(start, _)| start).max().unwrap_or(0);
+    Ok(first_in_class(first, period, earliest) as usize)
 }
 
 fn push_once_with_stop(modules: &mut Modules, stop_modules: &HashSet<String>) -> Option<String> {
@@ -487,39 +512,8 @@ fn push_once_with_stop(modules: &mut Modules, stop_modules: &HashSet<String>) ->
         }
 
         // Apply the pulse to the destination module, which can generate more pulses
-        if let Some(dest_module) = modules.get_mut(&pulse.end) {
-            let new_pulses = match dest_module {
-                Module::Broadcast(broadcast) => broadcast.apply(&pulse),
-                Module::FlipFlop(flipflop) => flipflop.apply(&pulse),
-                Module::Conjunction(conjunction) => conjunction.apply(&pulse),
-                _ => panic!("Illegal destination for pulse!"),
-            };
-
-            // Add the new pulses to the queue.
-            new_pulses
-                .into_iter()
-                .for_each(|pulse| queue.push_back(pulse));
-        }
+        queue.extend(apply_pulse(modules, &pulse));
     }
 
     stop_module
 }
-
-fn gcd(a: usize, b: usize) -> usize {
-    let mut first = a;
-    let mut second = b;
-
-    while first != second {
-        if first > second {
-            first = first - second;
-        } else {
-            second = second - first;
-        }
-    }
-
-    first
-}
-
-fn lcm(a: usize, b: usize) -> usize {
-    a / gcd(a, b) * b
-}
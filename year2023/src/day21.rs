@@ -1,87 +1,74 @@
 use std::collections::{HashMap, HashSet, VecDeque};
 
-use utilities;
+use utilities::{Grid, PuzzleError, PuzzleResult, Solution};
 
-pub fn run() {
-    utilities::run_puzzle(21, true, part_1, part_2);
-}
-
-type CharGrid = Vec<Vec<char>>;
-
-// Given a grid representing a garden with starting position 'S', gardens '.', and rocks '#', calculate
-// the number of positions that can be reached in 64 steps.
-fn part_1(data: &str) -> usize {
-    let grid = parse_input(data);
-
-    let starting_point = find_start(&grid).unwrap();
+pub struct Day21;
 
-    find_reachable_plots(&starting_point, 64, &grid)
-}
-
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-struct Point {
-    row: isize,
-    col: isize,
-}
-
-impl Point {
-    fn new(row: isize, col: isize) -> Self {
-        Self { row, col }
+impl Solution for Day21 {
+    fn day(&self) -> usize {
+        21
     }
 
-    fn get_row(&self, num_rows: usize) -> usize {
-        Point::adjust(self.row, num_rows as isize) as usize
+    fn title(&self) -> &'static str {
+        "Step Counter"
     }
 
-    fn get_col(&self, num_cols: usize) -> usize {
-        Point::adjust(self.col, num_cols as isize) as usize
+    fn part_1(&self, data: &str) -> PuzzleResult<String> {
+        part_1(data).map(|answer| answer.to_string())
     }
 
-    fn adjust(val: isize, max_val: isize) -> isize {
-        if val >= 0 {
-            val % max_val
-        } else {
-            ((val % max_val) + max_val) % max_val
-        }
+    fn part_2(&self, data: &str) -> PuzzleResult<String> {
+        part_2(data).map(|answer| answer.to_string())
     }
+}
 
-    fn component_wrap_level(val: isize, max_val: isize) -> isize {
-        if val >= 0 {
-            val / max_val
-        } else {
-            ((val.abs() - 1) / max_val) + 1
-        }
-    }
+type CharGrid = Grid<char>;
 
-    fn get_grid_coordinates(&self, num_rows: usize, num_cols: usize) -> Point {
-        let row = Point::component_wrap_level(self.row, num_rows as isize);
-        let col = Point::component_wrap_level(self.col, num_cols as isize);
+// Given a grid representing a garden with starting position 'S', gardens '.', and rocks '#', calculate
+// the number of positions that can be reached in 64 steps.
+fn part_1(data: &str) -> PuzzleResult<usize> {
+    let grid = CharGrid::parse(data)?;
 
-        Point::new(row, col)
-    }
+    let starting_point = find_start(&grid)?;
+
+    Ok(find_reachable_plots(&starting_point, 64, &grid))
 }
 
-fn parse_input(data: &str) -> CharGrid {
-    data.lines()
-        .map(|line| line.chars().collect())
-        .collect::<CharGrid>()
+type Point = utilities::Point<isize>;
+
+// Where a point on the endlessly tiled garden falls on the original grid.
+fn wrap(point: &Point, num_rows: usize, num_cols: usize) -> (usize, usize) {
+    (
+        point.row.rem_euclid(num_rows as isize) as usize,
+        point.col.rem_euclid(num_cols as isize) as usize,
+    )
 }
 
-fn find_start(grid: &CharGrid) -> Option<Point> {
-    for (row, row_data) in grid.iter().enumerate() {
-        for (col, &c) in row_data.iter().enumerate() {
-            if c == 'S' {
-                return Some(Point::new(row as isize, col as isize));
-            }
-        }
+fn component_wrap_level(val: isize, max_val: isize) -> isize {
+    if val >= 0 {
+        val / max_val
+    } else {
+        ((val.abs() - 1) / max_val) + 1
     }
+}
+
+// How many copies of the grid away from the original the point is, in each direction.
+fn get_grid_coordinates(point: &Point, num_rows: usize, num_cols: usize) -> Point {
+    let row = component_wrap_level(point.row, num_rows as isize);
+    let col = component_wrap_level(point.col, num_cols as isize);
+
+    Point::new(row, col)
+}
 
-    None
+fn find_start(grid: &CharGrid) -> PuzzleResult<Point> {
+    grid.find(&'S')
+        .map(|(row, col)| Point::new(row as isize, col as isize))
+        .ok_or_else(|| PuzzleError::parse("No S tile"))
 }
 
 fn find_reachable_plots(starting_point: &Point, max_steps: usize, grid: &CharGrid) -> usize {
-    let num_rows = grid.len();
-    let num_cols = grid[0].len();
+    let num_rows = grid.height();
+    let num_cols = grid.width();
 
     // Sounds like it can be a level-aware BFS?
     let mut queue: VecDeque<Point> = VecDeque::new();
@@ -101,7 +88,7 @@ fn find_reachable_plots(starting_point: &Point, max_steps: usize, grid: &CharGri
             let neighbors = get_neighbors(&current, num_rows, num_cols, false);
 
             for neighbor in neighbors {
-                if grid[neighbor.get_row(num_rows)][neighbor.get_col(num_cols)] != '#' {
+                if grid[wrap(&neighbor, num_rows, num_cols)] != '#' {
                     queue.push_back(neighbor);
                 }
             }
@@ -123,10 +110,6 @@ fn find_reachable_plots(starting_point: &Point, max_steps: usize, grid: &CharGri
         }
     }
 
-    if current_level <= max_steps {
-        println!("Exited early, ran out of locations to reach at level {current_level}");
-    }
-
     prev_level_size
 }
 
@@ -136,43 +119,40 @@ fn get_neighbors(
     num_cols: usize,
     allow_wrapping: bool,
 ) -> Vec<Point> {
-    let mut neighbors = vec![];
-
-    if current.row > 0 || allow_wrapping {
-        neighbors.push(Point::new(current.row - 1, current.col));
-    }
-
-    if current.col < (num_cols - 1) as isize || allow_wrapping {
-        neighbors.push(Point::new(current.row, current.col + 1));
-    }
-
-    if current.row < (num_rows - 1) as isize || allow_wrapping {
-        neighbors.push(Point::new(current.row + 1, current.col));
-    }
-
-    if current.col > 0 || allow_wrapping {
-        neighbors.push(Point::new(current.row, current.col - 1));
-    }
-
-    neighbors
+    current
+        .neighbors()
+        .filter(|neighbor| {
+            allow_wrapping
+                || ((0..num_rows as isize).contains(&neighbor.row)
+                    && (0..num_cols as isize).contains(&neighbor.col))
+        })
+        .collect()
 }
 
 // Grid can repeat indefinitely in any direction, and it just tiled.
-fn part_2(data: &str) -> usize {
-    let grid = parse_input(data);
+fn part_2(data: &str) -> PuzzleResult<usize> {
+    let grid = CharGrid::parse(data)?;
 
-    let starting_point = find_start(&grid).unwrap();
+    let starting_point = find_start(&grid)?;
 
     // Seems to spread in a diamond pattern, and that the max step value will perfectly inscribe an integer number
     // of grids.
-    let grid_data = get_grid_data(&grid, &starting_point);
-
-    find_far_reachable_plots(&grid_data, &starting_point, grid.len(), 26501365)
+    let grid_data = get_grid_data(&grid, &starting_point)?;
+
+    Ok(find_far_reachable_plots(
+        &grid_data,
+        &starting_point,
+        grid.height(),
+        26501365,
+    ))
 }
 
-fn get_grid_data(grid: &CharGrid, starting_point: &Point) -> Vec<Vec<(usize, usize)>> {
-    let num_rows = grid.len();
-    let num_cols = grid[0].len();
+fn get_grid_data(
+    grid: &CharGrid,
+    starting_point: &Point,
+) -> PuzzleResult<Vec<Vec<(usize, usize)>>> {
+    let num_rows = grid.height();
+    let num_cols = grid.width();
 
     let mut prev_points: HashSet<Point> = HashSet::new();
     let mut points: HashSet<Point> = HashSet::new();
@@ -181,7 +161,7 @@ fn get_grid_data(grid: &CharGrid, starting_point: &Point) -> Vec<Vec<(usize, usi
 
     // Gather the step data for processing. 5 is used to give enough information about the diagonals of the diamond.
     let scale = 5;
-    let mut step_data: Vec<Vec<i32>> = vec![vec![-1; num_cols * scale]; num_rows * scale];
+    let mut step_data = Grid::new(num_cols * scale, num_rows * scale, -1);
 
     set_step_data(&mut step_data, starting_point, 0, scale);
 
@@ -191,15 +171,18 @@ fn get_grid_data(grid: &CharGrid, starting_point: &Point) -> Vec<Vec<(usize, usi
         let new_points = points
             .iter()
             .flat_map(|&p| get_neighbors(&p, num_rows, num_cols, true))
-            .filter(|p| {
-                grid[p.get_row(num_rows)][p.get_col(num_cols)] != '#' && !prev_points.contains(p)
-            })
+            .filter(|p| grid[wrap(p, num_rows, num_cols)] != '#' && !prev_points.contains(p))
             .collect::<HashSet<Point>>();
 
+        // If S is walled in, the steps never reach the edge of the 5x5 grid.
+        if new_points.is_empty() {
+            return Err(PuzzleError::solve("The steps can't spread out from S"));
+        }
+
         // Check if the pattern has spread out of the 5x5 grid. The first will be any points that spill into rows -3, 3
         // or columns -3, 3.
         for point in &new_points {
-            let grid_point = point.get_grid_coordinates(num_rows, num_cols);
+            let grid_point = get_grid_coordinates(point, num_rows, num_cols);
 
             if grid_point.row.abs() == 3 || grid_point.col.abs() == 3 {
                 break 'step_loop;
@@ -216,12 +199,12 @@ fn get_grid_data(grid: &CharGrid, starting_point: &Point) -> Vec<Vec<(usize, usi
         step_count += 1;
     }
 
-    process_step_data(&step_data, grid, scale)
+    Ok(process_step_data(&step_data, grid, scale))
 }
 
-fn set_step_data(step_data: &mut Vec<Vec<i32>>, point: &Point, step: i32, scale: usize) {
-    let num_rows = step_data.len() as isize;
-    let num_cols = step_data[0].len() as isize;
+fn set_step_data(step_data: &mut Grid<i32>, point: &Point, step: i32, scale: usize) {
+    let num_rows = step_data.height() as isize;
+    let num_cols = step_data.width() as isize;
 
     let scale = scale as isize;
 
@@ -231,24 +214,25 @@ fn set_step_data(step_data: &mut Vec<Vec<i32>>, point: &Point, step: i32, scale:
     let row = point.row + row_bias;
     let col = point.col + col_bias;
 
+    // get_grid_data stops before the steps leave the 5x5 grid, and never steps back onto a point.
     assert!(row >= 0 && row < num_rows);
     assert!(col >= 0 && col < num_cols);
 
     let row = row as usize;
     let col = col as usize;
 
-    assert!(step_data[row][col] == -1);
+    assert!(step_data[(row, col)] == -1);
 
-    step_data[row][col] = step;
+    step_data[(row, col)] = step;
 }
 
 fn process_step_data(
-    step_data: &Vec<Vec<i32>>,
+    step_data: &Grid<i32>,
     grid: &CharGrid,
     scale: usize,
 ) -> Vec<Vec<(usize, usize)>> {
-    let num_rows = grid.len();
-    let num_cols = grid[0].len();
+    let num_rows = grid.height();
+    let num_cols = grid.width();
 
     let mut data = vec![vec![(0, 0); scale]; scale];
 
@@ -263,19 +247,19 @@ fn process_step_data(
     data
 }
 
-fn process_tile(step_data: &Vec<Vec<i32>>, grid: &CharGrid, point: &Point) -> (usize, usize) {
-    let num_rows = grid.len();
-    let num_cols = grid[0].len();
+fn process_tile(step_data: &Grid<i32>, grid: &CharGrid, point: &Point) -> (usize, usize) {
+    let num_rows = grid.height();
+    let num_cols = grid.width();
 
     let mut counts = HashMap::new();
 
     for ri in 0..num_rows {
         for ci in 0..num_cols {
-            if grid[ri][ci] == '#' {
+            if grid[(ri, ci)] == '#' {
                 continue;
             }
 
-            let val = step_data[point.row as usize + ri][point.col as usize + ci];
+            let val = step_data[(point.row as usize + ri, point.col as usize + ci)];
 
             counts.entry(val).and_modify(|v| *v += 1).or_insert(1);
         }
@@ -299,7 +283,7 @@ fn process_tile(step_data: &Vec<Vec<i32>>, grid: &CharGrid, point: &Point) -> (u
 }
 
 fn find_far_reachable_plots(
-    grid_data: &Vec<Vec<(usize, usize)>>,
+    grid_data: &[Vec<(usize, usize)>],
     starting_point: &Point,
     grid_len: usize,
     max_steps: usize,
@@ -334,11 +318,9 @@ fn find_far_reachable_plots(
         (grid_data[2][2].1, grid_data[2][2].0)
     };
 
-    let reachable_plots = radius * outer_diagonal
+    radius * outer_diagonal
         + (radius - 1) * inner_diagonal
         + corners
         + radius * radius * full_inside_odd
-        + (radius - 1) * (radius - 1) * full_inside_even;
-
-    reachable_plots
+        + (radius - 1) * (radius - 1) * full_inside_even
 }
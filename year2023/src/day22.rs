@@ -1,33 +1,35 @@
 use std::collections::{HashMap, HashSet, VecDeque};
-use std::ops::Range;
 
-use utilities;
+use utilities::{parse_number, Interval, Point3, PuzzleError, PuzzleResult, Solution};
 
-pub fn run() {
-    utilities::run_puzzle(22, true, part_1, part_2);
+pub struct Day22;
+
+impl Solution for Day22 {
+    fn day(&self) -> usize {
+        22
+    }
+
+    fn title(&self) -> &'static str {
+        "Sand Slabs"
+    }
+
+    fn part_1(&self, data: &str) -> PuzzleResult<String> {
+        part_1(data).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, data: &str) -> PuzzleResult<String> {
+        part_2(data).map(|answer| answer.to_string())
+    }
 }
 
 // Given a list of bricks specified as pairs of (x, y, z) coordinates representing the ends, determine
 // how many bricks can be individually disintegrated once they reach the ground (z == 0) and cause no
 // other bricks to shift positions. So, jenga.
-fn part_1(data: &str) -> usize {
-    let falling_bricks = parse_input(data);
+fn part_1(data: &str) -> PuzzleResult<usize> {
+    let falling_bricks = parse_input(data)?;
     let bricks = get_final_bricks(&falling_bricks);
 
-    count_disintegrated_bricks(&bricks)
-}
-
-#[derive(Debug, Clone)]
-struct Point {
-    x: usize,
-    y: usize,
-    z: usize,
-}
-
-impl Point {
-    fn new(x: usize, y: usize, z: usize) -> Self {
-        Self { x, y, z }
-    }
+    Ok(count_disintegrated_bricks(&bricks))
 }
 
 type BrickId = usize;
@@ -35,67 +37,71 @@ type BrickId = usize;
 #[derive(Debug, Clone)]
 struct Brick {
     id: BrickId,
-    start: Point,
-    end: Point,
+    start: Point3<usize>,
+    end: Point3<usize>,
 }
 
 impl Brick {
-    fn new(line: &str, id: BrickId) -> Self {
+    fn new(line: &str, id: BrickId) -> PuzzleResult<Self> {
         let coordinates = line
-            .split(|c: char| c == ',' || c == '~')
+            .split([',', '~'])
             .filter(|s| !s.is_empty())
-            .map(|s| s.parse::<usize>().unwrap())
-            .collect::<Vec<usize>>();
+            .map(parse_number)
+            .collect::<PuzzleResult<Vec<usize>>>()?;
 
-        Self {
-            id,
-            start: Point::new(coordinates[0], coordinates[1], coordinates[2]),
-            end: Point::new(coordinates[3], coordinates[4], coordinates[5]),
+        match coordinates[..] {
+            // The ground is at z = 0, so every brick starts above it.
+            [_, _, z1, _, _, z2] if z1 == 0 || z2 == 0 => {
+                Err(PuzzleError::parse("Bricks can't be at z = 0"))
+            }
+            [x1, y1, z1, x2, y2, z2] => Ok(Self {
+                id,
+                start: Point3::new(x1, y1, z1),
+                end: Point3::new(x2, y2, z2),
+            }),
+            _ => Err(PuzzleError::parse("Expected <X>,<Y>,<Z>~<X>,<Y>,<Z>")),
         }
     }
 
-    fn get_x_range(&self) -> Range<usize> {
+    fn get_x_range(&self) -> Interval<usize> {
         Brick::get_range(self.start.x, self.end.x)
     }
 
-    fn get_y_range(&self) -> Range<usize> {
+    fn get_y_range(&self) -> Interval<usize> {
         Brick::get_range(self.start.y, self.end.y)
     }
 
-    fn get_z_range(&self) -> Range<usize> {
+    fn get_z_range(&self) -> Interval<usize> {
         Brick::get_range(self.start.z, self.end.z)
     }
 
-    fn get_range(a: usize, b: usize) -> Range<usize> {
-        if a < b {
-            a..(b + 1)
-        } else {
-            b..(a + 1)
-        }
+    // The ends can be given either way round.
+    fn get_range(a: usize, b: usize) -> Interval<usize> {
+        Interval::inclusive(a.min(b), a.max(b))
     }
 
     fn move_to_z(&mut self, new_z: usize) {
         let z_min = self.get_z_range().start;
-        let delta_z = z_min - new_z;
 
-        self.start.z -= delta_z;
-        self.end.z -= delta_z;
+        // Overlapping bricks in the input can end up moving up rather than down.
+        self.start.z = self.start.z - z_min + new_z;
+        self.end.z = self.end.z - z_min + new_z;
     }
 }
 
-fn parse_input(data: &str) -> Vec<Brick> {
+fn parse_input(data: &str) -> PuzzleResult<Vec<Brick>> {
     let mut bricks = data
         .lines()
         .enumerate()
-        .map(|(id, line)| Brick::new(line, id))
-        .collect::<Vec<Brick>>();
+        .map(|(id, line)| Brick::new(line, id).map_err(|e| e.at_line(id + 1, line)))
+        .collect::<PuzzleResult<Vec<Brick>>>()?;
 
-    bricks.sort_by(|a, b| a.get_z_range().start.cmp(&b.get_z_range().start));
+    bricks.sort_by_key(|a| a.get_z_range().start);
 
-    bricks
+    Ok(bricks)
 }
 
-fn get_final_bricks(falling_bricks: &Vec<Brick>) -> Vec<Brick> {
+fn get_final_bricks(falling_bricks: &[Brick]) -> Vec<Brick> {
     // For each point along a brick's (x, y) footprint, check the "max" height of that location.
     let mut bricks = vec![];
 
@@ -132,7 +138,7 @@ fn get_final_bricks(falling_bricks: &Vec<Brick>) -> Vec<Brick> {
     bricks
 }
 
-fn count_disintegrated_bricks(bricks: &Vec<Brick>) -> usize {
+fn count_disintegrated_bricks(bricks: &[Brick]) -> usize {
     let bricks_below = get_bricks_below(bricks);
 
     let required_bricks = get_required_bricks(&bricks_below);
@@ -140,7 +146,7 @@ fn count_disintegrated_bricks(bricks: &Vec<Brick>) -> usize {
     bricks.len() - required_bricks.len()
 }
 
-fn get_bricks_below(bricks: &Vec<Brick>) -> HashMap<BrickId, HashSet<BrickId>> {
+fn get_bricks_below(bricks: &[Brick]) -> HashMap<BrickId, HashSet<BrickId>> {
     // Determine the brick arrangements in each column.
     let mut heights: HashMap<(usize, usize), HashMap<usize, BrickId>> = HashMap::new();
 
@@ -151,9 +157,7 @@ fn get_bricks_below(bricks: &Vec<Brick>) -> HashMap<BrickId, HashSet<BrickId>> {
             for y in brick.get_y_range() {
                 let key = (x, y);
 
-                if !heights.contains_key(&key) {
-                    heights.insert(key, HashMap::new());
-                }
+                heights.entry(key).or_default();
 
                 heights.get_mut(&key).unwrap().insert(brick_z, brick.id);
             }
@@ -193,7 +197,7 @@ fn get_required_bricks(bricks_below: &HashMap<BrickId, HashSet<BrickId>>) -> Has
     // If any brick has only 1 brick below, than that below brick is required.
     let mut required_bricks: HashSet<BrickId> = HashSet::new();
 
-    for (_, below) in bricks_below {
+    for below in bricks_below.values() {
         if below.len() == 1 {
             let required: &BrickId = below.iter().next().unwrap();
 
@@ -206,8 +210,8 @@ fn get_required_bricks(bricks_below: &HashMap<BrickId, HashSet<BrickId>>) -> Has
 
 // For each brick, determine the number of bricks that would fall if the given brick was disintegrated, and calculate
 // the sum of all bricks that would fall.
-fn part_2(data: &str) -> usize {
-    let falling_bricks = parse_input(data);
+fn part_2(data: &str) -> PuzzleResult<usize> {
+    let falling_bricks = parse_input(data)?;
 
     let bricks = get_final_bricks(&falling_bricks);
 
@@ -216,10 +220,10 @@ fn part_2(data: &str) -> usize {
 
     let required_bricks = get_required_bricks(&bricks_below);
 
-    required_bricks
+    Ok(required_bricks
         .iter()
         .map(|brick| count_above(*brick, &bricks_above, &bricks_below))
-        .sum()
+        .sum())
 }
 
 fn get_bricks_above(
@@ -0,0 +1,216 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use utilities::{dag_longest_paths, Direction, Grid, Point, PuzzleError, PuzzleResult, Solution};
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    fn day(&self) -> usize {
+        23
+    }
+
+    fn title(&self) -> &'static str {
+        "A Long Walk"
+    }
+
+    fn part_1(&self, data: &str) -> PuzzleResult<String> {
+        part_1(data).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, data: &str) -> PuzzleResult<String> {
+        part_2(data).map(|answer| answer.to_string())
+    }
+}
+
+type CharGrid = Grid<char>;
+type Graph = HashMap<Point, Vec<(Point, usize)>>;
+
+// Given a map of hiking trails, find the longest hike from the start to the end without going back
+// over tiles already visited.
+fn part_1(data: &str) -> PuzzleResult<usize> {
+    let grid = CharGrid::parse(data)?;
+
+    let (start, end) = find_ends(&grid)?;
+
+    // The grid can be thought of as a graph, where the paths are edges, and then any location where there
+    // is more than 1 way out is a node. Since there are the sliding points, the graph becomes a DAG. First
+    // walk through the grid to find the lengths of all edges, and then find the longest path.
+
+    let graph = build_graph(&grid, &start);
+
+    let longest = dag_longest_paths(start, |node| graph[node].clone())
+        .map_err(|_| PuzzleError::solve("The slopes don't stop the trails looping"))?;
+
+    longest
+        .cost(&end)
+        .ok_or_else(|| PuzzleError::solve("No trail reaches the end"))
+}
+
+// The slope tile that can only be crossed going in the direction.
+fn slope(direction: Direction) -> char {
+    match direction {
+        Direction::Up => '^',
+        Direction::Right => '>',
+        Direction::Down => 'v',
+        Direction::Left => '<',
+    }
+}
+
+fn find_ends(grid: &CharGrid) -> PuzzleResult<(Point, Point)> {
+    let find_path = |row: usize| {
+        grid.row(row)
+            .iter()
+            .position(|&c| c == '.')
+            .map(|col| Point::new(row, col))
+            .ok_or_else(|| {
+                let line = grid.row(row).iter().collect::<String>();
+
+                PuzzleError::parse("No path tile in the row").at_line(row + 1, &line)
+            })
+    };
+
+    Ok((find_path(0)?, find_path(grid.height() - 1)?))
+}
+
+// The path tiles next to the current one, other than back the way it came, and whether they can be
+// stepped on rather than being a slope facing the other way.
+fn get_neighbors(grid: &CharGrid, current: &Point, direction: &Direction) -> Vec<(Point, bool)> {
+    Direction::ALL
+        .into_iter()
+        .filter(|&next_direction| next_direction != direction.reverse())
+        .filter_map(|next_direction| {
+            let neighbor = grid.neighbor(*current, next_direction)?;
+            let tile = grid[neighbor];
+
+            (tile != '#').then(|| (neighbor, tile != slope(next_direction.reverse())))
+        })
+        .collect()
+}
+
+fn walk_path(grid: &CharGrid, start: &Point, direction: &Direction) -> (Point, usize, Direction) {
+    let mut step_count = 1;
+
+    let mut current = *start;
+    let mut current_direction = *direction;
+
+    loop {
+        let neighbors = get_neighbors(grid, &current, &current_direction);
+
+        if neighbors.len() != 1 {
+            break;
+        }
+
+        let (neighbor, _) = neighbors[0];
+
+        current_direction = current.direction_to(neighbor).unwrap();
+        current = neighbor;
+
+        step_count += 1;
+    }
+
+    (current, step_count, current_direction)
+}
+
+fn build_graph(grid: &CharGrid, start: &Point) -> Graph {
+    let mut graph = HashMap::new();
+    let mut paths = VecDeque::new();
+
+    paths.push_back((*start, Direction::Down));
+
+    let mut visited = HashSet::new();
+
+    while let Some((node, direction)) = paths.pop_front() {
+        if visited.contains(&node) {
+            continue;
+        }
+
+        graph.entry(node).or_insert_with(std::vec::Vec::new);
+
+        let neighbors = get_neighbors(grid, &node, &direction);
+
+        for (neighbor, moveable) in neighbors {
+            if !moveable {
+                continue;
+            }
+
+            let neighbor_direction = node.direction_to(neighbor).unwrap();
+
+            let (end_point, distance, end_direction) =
+                walk_path(grid, &neighbor, &neighbor_direction);
+
+            graph
+                .entry(node)
+                .and_modify(|v| v.push((end_point, distance)));
+
+            paths.push_back((end_point, end_direction));
+        }
+
+        visited.insert(node);
+    }
+
+    graph
+}
+
+// The slope parts aren't as slippery, so you can go up them. What is the longest path in this case? The graph
+// is now an undirected graph.
+fn part_2(data: &str) -> PuzzleResult<usize> {
+    let grid = CharGrid::parse(data)?;
+
+    let (start, end) = find_ends(&grid)?;
+
+    let directed_graph = build_graph(&grid, &start);
+    let graph = fill_graph(directed_graph);
+
+    Ok(dfs(&graph, &start, &end, &mut HashSet::new(), 0))
+}
+
+fn fill_graph(directed_graph: Graph) -> Graph {
+    let mut graph = HashMap::new();
+
+    for (point, neighbors) in directed_graph {
+        // for each point, add its neighbors, and add itself to each of it's neighbor's edges.
+        for (neighbor, dist) in neighbors.iter() {
+            let value = (point, *dist);
+            graph
+                .entry(*neighbor)
+                .and_modify(|v: &mut Vec<(Point, usize)>| v.push(value))
+                .or_insert(vec![value]);
+        }
+
+        graph
+            .entry(point)
+            .and_modify(|v| v.extend(neighbors.iter()))
+            .or_insert(neighbors);
+    }
+
+    graph
+}
+
+fn dfs(
+    graph: &Graph,
+    current: &Point,
+    end: &Point,
+    visited: &mut HashSet<Point>,
+    dist: usize,
+) -> usize {
+    if current == end {
+        return dist;
+    }
+
+    let mut end_distances = vec![];
+
+    // Get neighbors of current and try them all if they haven't been visited yet.
+    let neighbors = graph.get(current).unwrap();
+
+    for (neighbor, neighbor_dist) in neighbors {
+        if visited.contains(neighbor) {
+            continue;
+        }
+
+        visited.insert(*neighbor);
+        end_distances.push(dfs(graph, neighbor, end, visited, dist + neighbor_dist));
+        visited.remove(neighbor);
+    }
+
+    *end_distances.iter().max().unwrap_or(&0)
+}
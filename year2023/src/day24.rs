@@ -0,0 +1,283 @@
+use num::{BigRational, ToPrimitive};
+use utilities::{
+    parse_lines, parse_number, Matrix, MatrixError, PuzzleError, PuzzleResult, Solution,
+};
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    fn day(&self) -> usize {
+        24
+    }
+
+    fn title(&self) -> &'static str {
+        "Never Tell Me The Odds"
+    }
+
+    fn part_1(&self, data: &str) -> PuzzleResult<String> {
+        part_1(data).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, data: &str) -> PuzzleResult<String> {
+        part_2(data).map(|answer| answer.to_string())
+    }
+}
+
+// Given a list of hailstone positions and velocity vectors, calculate if their paths will intersect
+// in the test area. Only consider the X and Y axes.
+fn part_1(data: &str) -> PuzzleResult<usize> {
+    let hailstones = parse_input(data)?;
+
+    let test_min = 200000000000000;
+    let test_max = 400000000000000;
+
+    let mut intersection_count = 0;
+
+    for i in 0..(hailstones.len() - 1) {
+        let first = &hailstones[i];
+
+        for j in (i + 1)..hailstones.len() {
+            let second = &hailstones[j];
+
+            if check_intersection(first, second, test_min, test_max) {
+                intersection_count += 1
+            }
+        }
+    }
+
+    Ok(intersection_count)
+}
+
+// The positions and velocities are kept as integers so part 2 can work with them exactly.
+#[derive(Debug)]
+struct Hailstone {
+    px: i64,
+    py: i64,
+    pz: i64,
+    vx: i64,
+    vy: i64,
+    vz: i64,
+}
+
+impl Hailstone {
+    fn new(px: i64, py: i64, pz: i64, vx: i64, vy: i64, vz: i64) -> Self {
+        Self {
+            px,
+            py,
+            pz,
+            vx,
+            vy,
+            vz,
+        }
+    }
+
+    // The hailstone motion is given by the parametric equations x = px + vx * t and y = py + vy * t.
+
+    fn calc_x(&self, t: f64) -> f64 {
+        self.px as f64 + self.vx as f64 * t
+    }
+
+    fn calc_y(&self, t: f64) -> f64 {
+        self.py as f64 + self.vy as f64 * t
+    }
+
+    fn calc_t(&self, x: f64, y: f64) -> f64 {
+        // x = px + vx * t -> t = (x - px) / vx, or the same with y when the hailstone doesn't move
+        // along x.
+        if self.vx != 0 {
+            (x - self.px as f64) / self.vx as f64
+        } else {
+            (y - self.py as f64) / self.vy as f64
+        }
+    }
+}
+
+fn parse_input(data: &str) -> PuzzleResult<Vec<Hailstone>> {
+    let hailstones = parse_lines(data, |line| {
+        let pieces = line
+            .split(|c: char| c == ',' || c == '@' || c.is_ascii_whitespace())
+            .filter(|s| !s.is_empty())
+            .map(parse_number)
+            .collect::<PuzzleResult<Vec<i64>>>()?;
+
+        match pieces[..] {
+            [px, py, pz, vx, vy, vz] => Ok(Hailstone::new(px, py, pz, vx, vy, vz)),
+            _ => Err(PuzzleError::parse(
+                "Expected <PX>, <PY>, <PZ> @ <VX>, <VY>, <VZ>",
+            )),
+        }
+    })?;
+
+    if hailstones.is_empty() {
+        return Err(PuzzleError::parse("No hailstones"));
+    }
+
+    Ok(hailstones)
+}
+
+fn check_intersection(a: &Hailstone, b: &Hailstone, test_min: isize, test_max: isize) -> bool {
+    let bound_min = test_min as f64;
+    let bound_max = test_max as f64;
+
+    // calc_ta only gives crossings that happen in the future for a.
+    let Some(ta) = calc_ta(a, b) else {
+        return false;
+    };
+
+    let intersection_x = a.calc_x(ta);
+    let intersection_y = a.calc_y(ta);
+
+    let tb = b.calc_t(intersection_x, intersection_y);
+
+    if tb < 0.0 {
+        return false;
+    }
+
+    in_bounds(intersection_x, bound_min, bound_max)
+        && in_bounds(intersection_y, bound_min, bound_max)
+}
+
+fn in_bounds(val: f64, bound_min: f64, bound_max: f64) -> bool {
+    val >= bound_min && val <= bound_max
+}
+
+fn calc_ta(a: &Hailstone, b: &Hailstone) -> Option<f64> {
+    // The result of heavy algebra to solve for the parameter t_a of Hailstone a
+    // given the 4 parameteric equations of Hailstone a and Hailstone b.
+    let (a_px, a_py, a_vx, a_vy) = (a.px as f64, a.py as f64, a.vx as f64, a.vy as f64);
+    let (b_px, b_py, b_vx, b_vy) = (b.px as f64, b.py as f64, b.vx as f64, b.vy as f64);
+
+    let numerator = b_vx * (a_py - b_py) + b_vy * (b_px - a_px);
+    let denominator = b_vy * a_vx - b_vx * a_vy;
+
+    if denominator == 0.0 {
+        return None;
+    }
+
+    let fraction = numerator / denominator;
+
+    if fraction < 0.0 {
+        return None;
+    }
+
+    Some(fraction)
+}
+
+// Using the Z axis now, at what integer position is it possible to throw a rock with an
+// integer velocity such that it will collide with every hailstone? Calculate the sum of
+// the x, y, and z coordinates of the start point.
+//
+// This part was ridiculous. It took a while to get the right equations, but many iterations
+// to calculate the answer without significant rounding errors or overflowing, including
+// using i128 and writing a Rational number class. In the end, I had to bring in a crate to
+// to rational numbers with big integers to handle overflowing. This didn't feel good.
+//
+// The unknowns are the rock's R_px, R_py, R_pz, R_vx, R_vy and R_vz, and every pair of hailstones
+// gives two linear equations in them (see below). Pairing the first hailstone with each of the others
+// gives more equations than needed, which have to agree on the one answer. A few more than three
+// pairs are used in case some of them aren't independent.
+fn part_2(data: &str) -> PuzzleResult<i64> {
+    const MAX_PAIRS: usize = 8;
+
+    let hailstones = parse_input(data)?;
+
+    let (first, others) = hailstones
+        .split_first()
+        .ok_or_else(|| PuzzleError::solve("No hailstones"))?;
+
+    let (coefficients, constants): (Vec<[i128; 6]>, Vec<i128>) = others
+        .iter()
+        .take(MAX_PAIRS)
+        .flat_map(|other| {
+            [
+                build_equation_row(first, other, true),
+                build_equation_row(first, other, false),
+            ]
+        })
+        .unzip();
+
+    let matrix = Matrix::from_integers(6, &coefficients).map_err(rock_error)?;
+    let constants = constants
+        .into_iter()
+        .map(|constant| BigRational::from_integer(constant.into()))
+        .collect::<Vec<_>>();
+
+    let rock = matrix.solve(&constants).map_err(rock_error)?;
+
+    rock[..3]
+        .iter()
+        .map(|position| {
+            position
+                .is_integer()
+                .then(|| position.to_integer().to_i64())
+                .flatten()
+                .ok_or_else(|| PuzzleError::solve(format!("The rock starts at {position}")))
+        })
+        .sum()
+}
+
+fn rock_error(e: MatrixError) -> PuzzleError {
+    PuzzleError::solve(format!("Can't find the rock's path: {e}"))
+}
+
+// The coefficients of R_px, R_py, R_pz, R_vx, R_vy and R_vz, and the constant, from the x and y axes
+// or from the y and z axes.
+fn build_equation_row(a: &Hailstone, b: &Hailstone, xy: bool) -> ([i128; 6], i128) {
+    // (B_vy-A_vy)*R_px + (A_vx-B_vx)*R_py + (A_py-B_py)*R_vx + (B_px-A_px)*R_vy
+    // = A_py*A_vx - A_px*A_vy + B_px*B_vy - B_py*B_vx
+    let [apx, apy, apz, avx, avy, avz] = [a.px, a.py, a.pz, a.vx, a.vy, a.vz].map(i128::from);
+    let [bpx, bpy, bpz, bvx, bvy, bvz] = [b.px, b.py, b.pz, b.vx, b.vy, b.vz].map(i128::from);
+
+    if xy {
+        (
+            [bvy - avy, avx - bvx, 0, apy - bpy, bpx - apx, 0],
+            apy * avx - apx * avy + bpx * bvy - bpy * bvx,
+        )
+    } else {
+        (
+            [0, bvz - avz, avy - bvy, 0, apz - bpz, bpy - apy],
+            apz * avy - apy * avz + bpy * bvz - bpz * bvy,
+        )
+    }
+}
+
+// Given a hailstone A and rock R,
+// (A_px - R_px) / (R_vx - A_vx) == (A_py - R_py) / (R_vy - A_vy) == (A_pz - R_pz) / (R_vz - A_vz)
+//
+// Using x and y
+// (A_px - R_px) / (R_vx - A_vx) == (A_py - R_py) / (R_vy - A_vy)
+// (A_px - R_px)(R_vy - A_vy) == (A_py - R_py)(R_vx - A_vx)
+// (A_px * R_vy) - (A_px * A_vy) - (R_px * R_vy) + (R_px * A_vy) == (A_py * R_vx) - (A_py * A_vx) - (R_py * R_vx) + (R_py * A_vx)
+// (R_py * R_vx) - (R_px * R_vy) = (A_py * R_vx) - (A_py * A_vx) + (R_py * A_vx) - (A_px * R_vy) + (A_px * A_vy) - (R_px * A_vy)
+//
+// Using another hailstone B
+// (R_py * R_vx) - (R_px * R_vy) = (B_py * R_vx) - (B_py * B_vx) + (R_py * B_vx) - (B_px * R_vy) + (B_px * B_vy) - (R_px * B_vy)
+//
+// Setting the right sides equal
+//      A_py*R_vx - A_py*A_vx + R_py*A_vx - A_px*R_vy + A_px*A_vy - R_px*A_vy
+//    = B_py*R_vx - B_py*B_vx + R_py*B_vx - B_px*R_vy + B_px*B_vy - R_px*B_vy
+//
+// Subtracting the right side and factoring out the Rock components
+// (A_py-B_py)*R_vx - A_py*A_vx + B_py*B_vx + (A_vx-B_vx)*R_py - (A_px-B_px)*R_vy + A_px*A_vy - B_px*B_vy - (A_vy-B_vy)*R_px = 0
+//
+// Rearrange Rock components on the left
+// (B_vy-A_vy)*R_px + (A_vx-B_vx)*R_py + (A_py-B_py)*R_vx + (B_px-A_px)*R_vy = A_py*A_vx - A_px*A_vy + B_px*B_vy - B_py*B_vx
+//
+// Can repeat for other combinations
+// A: 19, 13, 30 @ -2,  1, -2
+// B: 18, 19, 22 @ -1, -1, -2
+// C: 20, 25, 34 @ -2, -2, -4
+// D: 12, 31, 28 @ -1, -2, -1
+// E: 20, 19, 15 @  1, -5, -3
+//
+// A,B: (19, 13, 30 @ -2,  1, -2) vs (18, 19, 22 @ -1, -1, -2)
+// (-1 - 1)R_px + (-2 - -1)R_py + (13 - 19)R_vx + (18 - 19)R_vy = (13 * -2) - (19 * 1) + (18 * -1) - (19 * -1)
+// -2R_px - R_py - 6R_vx - R_vy = -26 - 19 - 18 + 19 = -44
+//
+// With expected rock data: 24, 13, 10 @ -3, 1, 2
+// -2(24) - 13 - 6(-3) - 1 == -44
+// -48 - 13 + 18 -1 == -44
+//
+// A,C (19, 13, 30 @ -2,  1, -2) vs (20, 25, 34 @ -2, -2, -4)
+// (-2 - 1)R_px + (-2 - -2)R_py + (13 - 25)R_vx + (20 - 19)R_vy = (13 * -2) - (19 * 1) + (20 * -2) - (25 * -2)
+// -3R_px + 0R_py - 12R_vx + R_vy = -26 - 19 - 40 + 50 = -35
@@ -1,23 +1,45 @@
-use std::cmp::Reverse;
-use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::collections::{HashMap, HashSet};
 
-use utilities;
+use utilities::{bfs, key_values, InputFile, Parser, PuzzleError, PuzzleResult, Solution};
 
-pub fn run() {
-    utilities::run_puzzle(25, false, part_1, part_2)
+pub struct Day25;
+
+impl Solution for Day25 {
+    fn day(&self) -> usize {
+        25
+    }
+
+    fn title(&self) -> &'static str {
+        "Snowverload"
+    }
+
+    fn part_1(&self, data: &str) -> PuzzleResult<String> {
+        part_1(data).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, data: &str) -> PuzzleResult<String> {
+        Ok(part_2(data).to_string())
+    }
+
+    // The real input takes around 45 seconds.
+    fn default_input(&self) -> InputFile {
+        InputFile::Example(1)
+    }
 }
 
 type Graph = HashMap<String, HashSet<String>>;
 
 // Given a wiring diagram of "module: <other modules>", where connections are undirectional, cut 3 connections
 // such that the result is 2 disconnected groups. Return the product of the group sizes.
-fn part_1(data: &str) -> usize {
-    let graph = build_graph(data);
+fn part_1(data: &str) -> PuzzleResult<usize> {
+    let graph = build_graph(data)?;
 
     // Start with some node, prepare the first set and set of other nodes
     let mut nodes = graph.keys().cloned();
 
-    let first_node = nodes.next().unwrap();
+    let Some(first_node) = nodes.next() else {
+        return Err(PuzzleError::parse("No components"));
+    };
 
     let mut first_set = HashSet::new();
 
@@ -32,7 +54,7 @@ fn part_1(data: &str) -> usize {
         let mut used_graph = Graph::new();
 
         for i in 0..=edge_count {
-            if let Some(path) = find_path(&graph, &used_graph, &first_node, &node) {
+            if let Some(path) = find_path(&graph, &used_graph, &first_node, node) {
                 // Found a path
                 update_used_path(&path, &mut used_graph);
 
@@ -45,82 +67,44 @@ fn part_1(data: &str) -> usize {
                     // On the other side
                     second_set.insert(node.clone());
                 } else {
-                    println!("Error! Didnt' find a path on iteration {i}!");
+                    return Err(PuzzleError::solve(format!(
+                        "Didn't find a path on iteration {i}"
+                    )));
                 }
             }
         }
     }
 
-    first_set.len() * second_set.len()
+    Ok(first_set.len() * second_set.len())
 }
 
+// The shortest path from the start to the end that doesn't use any of the used connections.
 fn find_path(
     graph: &Graph,
     used_graph: &Graph,
-    start_node: &String,
-    end_node: &String,
+    start_node: &str,
+    end_node: &str,
 ) -> Option<Vec<String>> {
-    let mut visited = HashSet::new();
-    let mut distances: HashMap<String, (u32, Option<String>)> = HashMap::new();
-
-    let mut queue = BinaryHeap::new();
-    queue.push((Reverse(0), start_node.clone(), None));
-
-    let mut found = false;
-
-    while let Some((Reverse(dist), node, parent)) = queue.pop() {
-        // Have a node and its distance.
-        if visited.contains(&node) {
-            continue;
-        }
-
-        // Haven't processed this node yet.
-        distances.insert(node.clone(), (dist, parent));
-
-        if &node == end_node {
-            found = true;
-            break;
-        }
+    let search = bfs(
+        [start_node.to_string()],
+        |node| {
+            let used_neighbors = used_graph.get(node);
 
-        // Process neighbors
-        if let Some(neighbors) = graph.get(&node) {
-            for neighbor in neighbors {
-                // Check for edges that are already used.
-                if let Some(used_neighbors) = used_graph.get(&node) {
-                    if used_neighbors.contains(neighbor) {
-                        continue;
-                    }
-                }
-
-                // Found some neighbors we could go to
-                let new_dist = dist + 1;
-
-                queue.push((Reverse(new_dist), neighbor.clone(), Some(node.clone())));
-            }
-        }
-
-        visited.insert(node);
-    }
-
-    if !found {
-        return None;
-    }
-
-    let mut current = Some(end_node);
-    let mut path = vec![];
-
-    while let Some(node) = current {
-        path.push(node.clone());
-
-        let (_, new_current) = distances.get(node).unwrap();
-
-        current = new_current.as_ref();
-    }
-
-    Some(path.into_iter().rev().collect())
+            graph
+                .get(node)
+                .into_iter()
+                .flatten()
+                .filter(|&neighbor| used_neighbors.is_none_or(|used| !used.contains(neighbor)))
+                .cloned()
+                .collect::<Vec<String>>()
+        },
+        |node| node == end_node,
+    );
+
+    search.path_to(&end_node.to_string())
 }
 
-fn update_used_path(path: &Vec<String>, used_graph: &mut Graph) {
+fn update_used_path(path: &[String], used_graph: &mut Graph) {
     for (src, dest) in path.iter().zip(path.iter().skip(1)) {
         used_graph
             .entry(src.clone())
@@ -138,18 +122,16 @@ fn update_used_path(path: &Vec<String>, used_graph: &mut Graph) {
     }
 }
 
-fn build_graph(data: &str) -> Graph {
+fn build_graph(data: &str) -> PuzzleResult<Graph> {
     let mut graph: Graph = HashMap::new();
 
-    for line in data.lines() {
-        let pieces = line.split(":").collect::<Vec<_>>();
-
-        let dest = pieces[1]
-            .split_ascii_whitespace()
+    for (start_node, dest) in key_values(data, ":", |p| Ok(p.many(Parser::word)))? {
+        let dest = dest
+            .into_iter()
             .map(|s| s.to_string())
             .collect::<HashSet<String>>();
 
-        let start_node = pieces[0].to_string();
+        let start_node = start_node.to_string();
 
         let dest_nodes: HashSet<String> = dest.iter().cloned().collect();
 
@@ -170,7 +152,7 @@ fn build_graph(data: &str) -> Graph {
         }
     }
 
-    graph
+    Ok(graph)
 }
 
 fn part_2(_data: &str) -> usize {
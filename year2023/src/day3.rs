@@ -0,0 +1,98 @@
+use std::collections::{HashMap, HashSet};
+
+use utilities::{Grid, PuzzleResult, Solution};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    fn day(&self) -> usize {
+        3
+    }
+
+    fn title(&self) -> &'static str {
+        "Gear Ratios"
+    }
+
+    fn part_1(&self, data: &str) -> PuzzleResult<String> {
+        part_1(data).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, data: &str) -> PuzzleResult<String> {
+        part_2(data).map(|answer| answer.to_string())
+    }
+}
+
+// A number on the schematic, with the cells around it.
+struct PartNumber {
+    value: u32,
+    neighbors: HashSet<(usize, usize)>,
+}
+
+// Finds the numbers row by row, gathering the cells around each of their digits.
+fn find_numbers(grid: &Grid<char>) -> Vec<PartNumber> {
+    let mut numbers = vec![];
+
+    for row in 0..grid.height() {
+        let mut current: Option<PartNumber> = None;
+
+        for col in 0..grid.width() {
+            match grid[(row, col)].to_digit(10) {
+                Some(digit) => {
+                    let number = current.get_or_insert_with(|| PartNumber {
+                        value: 0,
+                        neighbors: HashSet::new(),
+                    });
+
+                    number.value = number.value * 10 + digit;
+                    number.neighbors.extend(grid.neighbors8((row, col)));
+                }
+                None => numbers.extend(current.take()),
+            }
+        }
+
+        numbers.extend(current);
+    }
+
+    numbers
+}
+
+fn is_symbol(c: char) -> bool {
+    !c.is_ascii_digit() && c != '.'
+}
+
+// Sum all of the numbers that are next to a symbol, including diagonally.
+fn part_1(data: &str) -> PuzzleResult<u32> {
+    let grid = Grid::parse(data)?;
+
+    Ok(find_numbers(&grid)
+        .iter()
+        .filter(|number| {
+            number
+                .neighbors
+                .iter()
+                .any(|&position| is_symbol(grid[position]))
+        })
+        .map(|number| number.value)
+        .sum())
+}
+
+// Find the sum of all gear ratios, where a gear is a * with exactly 2 adjacent parts.
+fn part_2(data: &str) -> PuzzleResult<u32> {
+    let grid = Grid::parse(data)?;
+
+    let mut gears: HashMap<(usize, usize), Vec<u32>> = HashMap::new();
+
+    for number in find_numbers(&grid) {
+        for &position in &number.neighbors {
+            if grid[position] == '*' {
+                gears.entry(position).or_default().push(number.value);
+            }
+        }
+    }
+
+    Ok(gears
+        .values()
+        .filter(|numbers| numbers.len() == 2)
+        .map(|numbers| numbers[0] * numbers[1])
+        .sum())
+}
@@ -0,0 +1,145 @@
+use std::{collections::HashMap, collections::HashSet};
+
+use utilities::{parse_number, PuzzleError, PuzzleResult, Solution};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    fn day(&self) -> usize {
+        4
+    }
+
+    fn title(&self) -> &'static str {
+        "Scratchcards"
+    }
+
+    fn part_1(&self, data: &str) -> PuzzleResult<String> {
+        part_1(data).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, data: &str) -> PuzzleResult<String> {
+        part_2(data).map(|answer| answer.to_string())
+    }
+}
+
+fn part_1(data: &str) -> PuzzleResult<u32> {
+    // line: Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+
+    let mut points: u32 = 0;
+
+    let matching_numbers = find_matching_numbers(data)?;
+
+    for (_, num_matching) in matching_numbers {
+        if num_matching != 0 {
+            points += 2_u32.pow(num_matching - 1);
+        }
+    }
+
+    Ok(points)
+}
+
+fn parse_numbers(data: &str) -> PuzzleResult<HashSet<u32>> {
+    data.split_ascii_whitespace().map(parse_number).collect()
+}
+
+fn find_matching_numbers(data: &str) -> PuzzleResult<HashMap<u32, u32>> {
+    let mut matching_numbers: HashMap<u32, u32> = HashMap::new();
+
+    for (i, line) in data.lines().enumerate() {
+        let (card_number, winning_numbers, card_numbers) =
+            parse_card(line).map_err(|e| e.at_line(i + 1, line))?;
+
+        let intersection_size = winning_numbers
+            .intersection(&card_numbers)
+            .collect::<Vec<&u32>>()
+            .len() as u32;
+
+        matching_numbers.insert(card_number, intersection_size);
+    }
+
+    Ok(matching_numbers)
+}
+
+fn parse_card(line: &str) -> PuzzleResult<(u32, HashSet<u32>, HashSet<u32>)> {
+    let (first_piece, card_numbers) = line
+        .split_once("|")
+        .ok_or_else(|| PuzzleError::parse("Missing '|'"))?;
+
+    let (card_title, winning_numbers) = first_piece
+        .split_once(":")
+        .ok_or_else(|| PuzzleError::parse("Missing ':'"))?;
+
+    let card_number = match card_title.split_once(" ") {
+        Some(("Card", number)) => parse_number(number)?,
+        _ => return Err(PuzzleError::parse(format!("Illegal card [{card_title}]"))),
+    };
+
+    Ok((
+        card_number,
+        parse_numbers(winning_numbers)?,
+        parse_numbers(card_numbers)?,
+    ))
+}
+
+fn part_2(data: &str) -> PuzzleResult<u32> {
+    let matching_numbers = find_matching_numbers(data)?;
+
+    // 1->4, 2->2, 3->2, 4->1, 5->0, 6->0
+    // println!("matching_numbers {:?}", matching_numbers);
+
+    // println!("num cards {}", matching_numbers.len());
+
+    let mut card_counts = CardCounts::new(matching_numbers.len());
+
+    for i in (1..=matching_numbers.len()).rev() {
+        let Some(num_matches) = matching_numbers.get(&(i as u32)) else {
+            return Err(PuzzleError::solve(format!("Missing card {i}")));
+        };
+
+        card_counts.calc_cards(i, *num_matches)?;
+    }
+
+    // println!("card_counts {:?}", card_counts);
+
+    let mut total = 0;
+    for i in 1..=matching_numbers.len() {
+        total += card_counts.get_count(i);
+    }
+
+    // 9903606 is wrong for part 2
+    // 9924412
+    Ok(total)
+}
+
+struct CardCounts {
+    counts: Vec<u32>,
+}
+
+impl CardCounts {
+    fn new(num_cards: usize) -> Self {
+        Self {
+            counts: vec![0; num_cards],
+        }
+    }
+
+    fn get_count(&self, card_id: usize) -> u32 {
+        self.counts[card_id - 1]
+    }
+
+    fn calc_cards(&mut self, card_id: usize, num_matches: u32) -> PuzzleResult<()> {
+        // If num_matches is 0, only win this card.
+        // Else, win the next num_matches cards, which have to be in the table.
+        let won = self
+            .counts
+            .get(card_id..card_id + num_matches as usize)
+            .ok_or_else(|| {
+                PuzzleError::solve(format!(
+                    "Card {card_id} wins cards past the end of the table"
+                ))
+            })?;
+
+        self.counts[card_id - 1] = 1 + won.iter().sum::<u32>();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,173 @@
+use utilities::{
+    sections, Interval, Parser, PuzzleError, PuzzleResult, RangeSet, Section, Solution,
+};
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    fn day(&self) -> usize {
+        5
+    }
+
+    fn title(&self) -> &'static str {
+        "If You Give A Seed A Fertilizer"
+    }
+
+    fn part_1(&self, data: &str) -> PuzzleResult<String> {
+        part_1(data).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, data: &str) -> PuzzleResult<String> {
+        part_2(data).map(|answer| answer.to_string())
+    }
+}
+
+// Example data:
+// seeds: 79 14 55 13
+//
+// seed-to-soil map:
+// 50 98 2
+// 52 50 48
+//
+// The map lines are <DEST_START> <SOURCE_START> <LEN>, and it's linear in that range.
+// ex: 50 98 2
+//     DEST_START=50, SOURCE_START=98, LEN=2 ->
+//      98 <= seed <= 99 -> seed - 98 + 50
+//      seed 98 -> soil 50
+//      seed 99 -> soil 51
+//     52 50 48
+//     DEST_START=52, SOURCE_START=50, LEN=48 ->
+//      50 <= seed <= 97 -> seed - 50 + 52
+//      seed 50 -> soil 52
+//      ...
+//      seed 97 -> soil
+//     If doesn't match a range, then DEST = SOURCE
+//      SOURCE_START <= seed <= SOURCE_START + LEN - 1 -> seed - SOURCE_START + DEST_START
+
+fn part_1(data: &str) -> PuzzleResult<isize> {
+    let (seeds, maps) = parse_input(data, parse_seeds)?;
+
+    let seeds = seeds
+        .iter()
+        .map(|&seed| Interval::new(seed, seed + 1))
+        .collect();
+
+    lowest_location(seeds, &maps)
+}
+
+fn parse_seeds(line: &str) -> PuzzleResult<Vec<isize>> {
+    Parser::parse(line, |p| {
+        p.token("seeds:")?;
+
+        Ok(p.many(Parser::int))
+    })
+}
+
+// The seeds line is the first section, and each of the others is a map.
+fn parse_input<T>(
+    data: &str,
+    parse_seeds: impl Fn(&str) -> PuzzleResult<T>,
+) -> PuzzleResult<(T, Vec<Map>)> {
+    let sections = sections(data);
+
+    let Some((seeds, maps)) = sections.split_first() else {
+        return Err(PuzzleError::parse("Expected seeds: <numbers>"));
+    };
+
+    if let Some((number, line)) = seeds.lines().nth(1) {
+        return Err(
+            PuzzleError::parse("Expected a blank line after the seeds").at_line(number, line)
+        );
+    }
+
+    let seeds = parse_seeds(seeds.text).map_err(|e| e.at_line(seeds.first_line, seeds.text))?;
+    let maps = maps.iter().map(Map::new).collect::<PuzzleResult<_>>()?;
+
+    Ok((seeds, maps))
+}
+
+// The source values a map line covers, and how far it moves them.
+#[derive(Debug)]
+struct Range {
+    source: Interval<isize>,
+    delta: isize,
+}
+
+impl Range {
+    fn new(dest: isize, start: isize, len: isize) -> Self {
+        Self {
+            source: Interval::new(start, start + len),
+            delta: dest - start,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Map {
+    ranges: Vec<Range>,
+}
+
+impl Map {
+    fn new(section: &Section) -> PuzzleResult<Self> {
+        let mut lines = section.lines();
+
+        if let Some((number, header)) = lines.next() {
+            Parser::parse(header, |p| {
+                p.list("-", Parser::word)?;
+                p.token("map:")
+            })
+            .map_err(|e| e.at_line(number, header))?;
+        }
+
+        let ranges = lines
+            .map(|(number, line)| {
+                Parser::parse(line, |p| Ok(Range::new(p.int()?, p.int()?, p.int()?)))
+                    .map_err(|e| e.at_line(number, line))
+            })
+            .collect::<PuzzleResult<_>>()?;
+
+        Ok(Self { ranges })
+    }
+
+    // Moves the part of the input that each range covers, and leaves the rest where it is.
+    fn map(&self, input: &RangeSet<isize>) -> RangeSet<isize> {
+        let mut unmapped = input.clone();
+        let mut result = RangeSet::new();
+
+        for range in &self.ranges {
+            let source = RangeSet::from(range.source);
+
+            result = result.union(&unmapped.intersection(&source).shift(range.delta));
+            unmapped = unmapped.difference(&source);
+        }
+
+        result.union(&unmapped)
+    }
+}
+
+// Pushes the seeds through every map, working on whole ranges of seeds at a time.
+fn lowest_location(seeds: RangeSet<isize>, maps: &[Map]) -> PuzzleResult<isize> {
+    maps.iter()
+        .fold(seeds, |values, map| map.map(&values))
+        .min()
+        .ok_or_else(|| PuzzleError::solve("No seeds"))
+}
+
+fn part_2(data: &str) -> PuzzleResult<isize> {
+    let (seeds, maps) = parse_input(data, parse_seeds_v2)?;
+
+    lowest_location(seeds, &maps)
+}
+
+fn parse_seeds_v2(line: &str) -> PuzzleResult<RangeSet<isize>> {
+    let seed_numbers = parse_seeds(line)?;
+
+    if seed_numbers.len() % 2 != 0 {
+        return Err(PuzzleError::parse("Expected pairs of <START> <LEN>"));
+    }
+
+    Ok(seed_numbers
+        .chunks(2)
+        .map(|pair| Interval::new(pair[0], pair[0] + pair[1]))
+        .collect())
+}
@@ -0,0 +1,162 @@
+use utilities::{parse_lines, parse_number, PuzzleError, PuzzleResult, Solution};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    fn day(&self) -> usize {
+        6
+    }
+
+    fn title(&self) -> &'static str {
+        "Wait For It"
+    }
+
+    fn part_1(&self, data: &str) -> PuzzleResult<String> {
+        part_1(data).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, data: &str) -> PuzzleResult<String> {
+        part_2(data).map(|answer| answer.to_string())
+    }
+}
+
+// This is an algebraic optimization problem. For a given race of time T and best distance B,
+// holding the button for x seconds gives x mm/ms speed, which travels for (T - x) ms. The
+// distance travelled is then given by:
+//      distance = x * (T - x) = Tx - x^2
+//
+// In order to beat the be distance, the equation needs to be:
+//      B < Tx - x^2
+//  ->  -x^2 - Tx - B > 0
+//
+// The quadratic equation can be used to find the min and max values of x that will beat the
+// best distance.
+
+fn part_1(data: &str) -> PuzzleResult<usize> {
+    let races = parse_races(data)?;
+
+    let mut combos = 1;
+    for race in races {
+        combos *= race.get_best_time_range();
+    }
+
+    Ok(combos)
+}
+
+#[derive(Debug)]
+struct Race {
+    time: usize,
+    best_distance: usize,
+}
+
+impl Race {
+    fn new(time: usize, best_distance: usize) -> Self {
+        Self {
+            time,
+            best_distance,
+        }
+    }
+
+    fn get_best_time_range(&self) -> usize {
+        let b = self.time as f64;
+        let c = self.best_distance as f64;
+
+        let discriminant = b.powf(2.0) - 4.0 * c;
+
+        // Without two different roots, no time gets past the best distance.
+        if discriminant <= 0.0 {
+            return 0;
+        }
+
+        let sqrt_body: f64 = discriminant.sqrt();
+
+        let min = (-b + sqrt_body) / -2.0;
+        let max = (-b - sqrt_body) / -2.0;
+
+        // Since the boat must not tie with the best time, it must be greater than the best
+        // distance. In the case of a tie, move to the next integer for the min and the previous
+        // integer for the max.
+        let min = if min % 1.0 == 0.0 {
+            min + 1.0
+        } else {
+            min.ceil()
+        };
+
+        let max = if max % 1.0 == 0.0 {
+            max - 1.0
+        } else {
+            max.floor()
+        };
+
+        if max < min {
+            0
+        } else {
+            (max - min) as usize + 1
+        }
+    }
+}
+
+fn parse_races(data: &str) -> PuzzleResult<Vec<Race>> {
+    let numbers = parse_lines(data, parse_numbers)?;
+
+    let [times, distances] = &numbers[..] else {
+        return Err(PuzzleError::parse(
+            "Expected a Time line and a Distance line",
+        ));
+    };
+
+    if times.len() != distances.len() {
+        return Err(PuzzleError::parse(
+            "Different number of times and distances",
+        ));
+    }
+
+    let mut races = vec![];
+    for (time, distance) in times.iter().zip(distances.iter()) {
+        races.push(Race::new(*time, *distance));
+    }
+
+    Ok(races)
+}
+
+fn number_data(line: &str) -> PuzzleResult<&str> {
+    match line.split_once(":") {
+        Some(("Time" | "Distance", number_data)) => Ok(number_data),
+        _ => Err(PuzzleError::parse("Expected Time: or Distance:")),
+    }
+}
+
+fn parse_numbers(line: &str) -> PuzzleResult<Vec<usize>> {
+    number_data(line)?
+        .split_ascii_whitespace()
+        .map(parse_number)
+        .collect()
+}
+
+fn part_2(data: &str) -> PuzzleResult<usize> {
+    let race = parse_races_v2(data)?;
+
+    Ok(race.get_best_time_range())
+}
+
+fn parse_races_v2(data: &str) -> PuzzleResult<Race> {
+    let numbers = parse_lines(data, parse_numbers_v2)?;
+
+    let [time, distance] = numbers[..] else {
+        return Err(PuzzleError::parse(
+            "Expected a Time line and a Distance line",
+        ));
+    };
+
+    Ok(Race::new(time, distance))
+}
+
+fn parse_numbers_v2(line: &str) -> PuzzleResult<usize> {
+    let numbers = number_data(line)?
+        .split_ascii_whitespace()
+        .collect::<Vec<&str>>();
+
+    let number = numbers.iter().fold(String::new(), |s, piece| s + piece);
+
+    parse_number(&number)
+}
@@ -1,23 +1,39 @@
 use std::collections::HashMap;
 
-use utilities;
+use utilities::{parse_lines, parse_number, PuzzleError, PuzzleResult, Solution};
 
-pub fn run() {
-    utilities::run_puzzle(7, true, part_1, part_2);
+pub struct Day7;
+
+impl Solution for Day7 {
+    fn day(&self) -> usize {
+        7
+    }
+
+    fn title(&self) -> &'static str {
+        "Camel Cards"
+    }
+
+    fn part_1(&self, data: &str) -> PuzzleResult<String> {
+        part_1(data).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, data: &str) -> PuzzleResult<String> {
+        part_2(data).map(|answer| answer.to_string())
+    }
 }
 
 // Sort the hands by type, breaking ties by card ranks.
-fn part_1(data: &str) -> usize {
-    let mut hands = parse_hands(data, false);
+fn part_1(data: &str) -> PuzzleResult<usize> {
+    let mut hands = parse_hands(data, false)?;
 
-    hands.sort_by(|a, b| a.partial_cmp(&b).unwrap());
+    hands.sort_by(|a, b| a.partial_cmp(b).unwrap());
 
     let total_score = hands
         .iter()
         .enumerate()
         .fold(0, |count, (i, hand)| count + (i + 1) * hand.bid);
 
-    total_score
+    Ok(total_score)
 }
 
 #[derive(Debug)]
@@ -255,32 +271,31 @@ impl PartialEq for Hand {
     }
 }
 
-fn parse_hands(data: &str, j_as_joker: bool) -> Vec<Hand> {
-    let mut hands = vec![];
-
-    for line in data.lines() {
-        let mut pieces_iter = line.split_ascii_whitespace();
-
-        let hand = pieces_iter.next().unwrap();
-        let bid = pieces_iter.next().unwrap().parse::<usize>().unwrap();
+fn parse_hands(data: &str, j_as_joker: bool) -> PuzzleResult<Vec<Hand>> {
+    parse_lines(data, |line| {
+        let Some((hand, bid)) = line.split_once(' ') else {
+            return Err(PuzzleError::parse("Expected <HAND> <BID>"));
+        };
 
-        hands.push(Hand::new(hand, bid, j_as_joker));
-    }
+        if hand.len() != 5 || !hand.chars().all(|c| "23456789TJQKA".contains(c)) {
+            return Err(PuzzleError::parse(format!("Illegal hand [{hand}]")));
+        }
 
-    hands
+        Ok(Hand::new(hand, parse_number(bid)?, j_as_joker))
+    })
 }
 
 // J is now a Joker, which can act as any other card to make the hand have a stronger type. For
 // matching hand types, it acts as the weakest card when comparing.
-fn part_2(data: &str) -> usize {
-    let mut hands = parse_hands(data, true);
+fn part_2(data: &str) -> PuzzleResult<usize> {
+    let mut hands = parse_hands(data, true)?;
 
-    hands.sort_by(|a, b| a.partial_cmp_v2(&b).unwrap());
+    hands.sort_by(|a, b| a.partial_cmp_v2(b).unwrap());
 
     let total_score = hands
         .iter()
         .enumerate()
         .fold(0, |count, (i, hand)| count + (i + 1) * hand.bid);
 
-    total_score
+    Ok(total_score)
 }
@@ -0,0 +1,190 @@
+use std::collections::{HashMap, HashSet};
+
+use utilities::{crt, first_in_class, PuzzleError, PuzzleResult, Solution};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    fn day(&self) -> usize {
+        8
+    }
+
+    fn title(&self) -> &'static str {
+        "Haunted Wasteland"
+    }
+
+    fn part_1(&self, data: &str) -> PuzzleResult<String> {
+        part_1(data).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, data: &str) -> PuzzleResult<String> {
+        part_2(data).map(|answer| answer.to_string())
+    }
+}
+
+fn part_1(data: &str) -> PuzzleResult<usize> {
+    let (instructions, node_map) = parse_data(data)?;
+
+    if !node_map.contains_key("AAA") {
+        return Err(PuzzleError::solve("Missing node AAA"));
+    }
+
+    let mut current_node = "AAA";
+    let mut count = 0;
+
+    // Once a pass through the instructions starts from the same node again, the walk repeats.
+    let mut pass_starts = HashSet::new();
+
+    while pass_starts.insert(current_node) {
+        for direction in instructions.chars() {
+            // Parsing has already checked the directions and that every neighbor is a node.
+            let (left, right) = node_map[current_node];
+
+            current_node = if direction == 'L' { left } else { right };
+
+            count += 1;
+
+            if current_node == "ZZZ" {
+                return Ok(count);
+            }
+        }
+    }
+
+    Err(PuzzleError::solve("ZZZ can't be reached from AAA"))
+}
+
+type NodeMap<'a> = HashMap<&'a str, (&'a str, &'a str)>;
+
+// Parses the instructions and the nodes, checking that every neighbor is also a node so that
+// walking the map can't get lost.
+fn parse_data(data: &str) -> PuzzleResult<(&str, NodeMap<'_>)> {
+    let mut line_iter = data.lines().enumerate();
+
+    let instructions = line_iter.next().map_or("", |(_, line)| line);
+
+    if instructions.is_empty() || !instructions.chars().all(|c| c == 'L' || c == 'R') {
+        return Err(PuzzleError::parse("Expected L and R instructions").at_line(1, instructions));
+    }
+
+    line_iter.next();
+
+    let mut node_map: NodeMap = HashMap::new();
+    let mut node_lines = vec![];
+
+    for (i, line) in line_iter {
+        let (node_name, neighbors) = parse_node(line).map_err(|e| e.at_line(i + 1, line))?;
+
+        node_map.insert(node_name, neighbors);
+        node_lines.push((i, line, neighbors));
+    }
+
+    for (i, line, (left, right)) in node_lines {
+        for neighbor in [left, right] {
+            if !node_map.contains_key(neighbor) {
+                return Err(
+                    PuzzleError::parse(format!("Unknown node [{neighbor}]")).at_line(i + 1, line)
+                );
+            }
+        }
+    }
+
+    Ok((instructions, node_map))
+}
+
+fn parse_node(line: &str) -> PuzzleResult<(&str, (&str, &str))> {
+    let (node_name, neighbors) = line
+        .split_once("=")
+        .ok_or_else(|| PuzzleError::parse("Expected <NODE> = (<LEFT>, <RIGHT>)"))?;
+
+    Ok((node_name.trim(), parse_node_neighbors(neighbors.trim())?))
+}
+
+fn parse_node_neighbors(neighbors: &str) -> PuzzleResult<(&str, &str)> {
+    neighbors
+        .strip_prefix("(")
+        .and_then(|s| s.strip_suffix(")"))
+        .and_then(|s| s.split_once(","))
+        .map(|(left, right)| (left.trim(), right.trim()))
+        .ok_or_else(|| PuzzleError::parse(format!("Illegal neighbors [{neighbors}]")))
+}
+
+// This was tricky. Originally I started with finding each start and advancing one at a time from there
+// but that was taking forever with no end in sight. Each ghost ends up going round a cycle, so the
+// answer is the first step that lines up with an end node on every cycle.
+fn part_2(data: &str) -> PuzzleResult<usize> {
+    let (instructions, node_map) = parse_data(data)?;
+
+    let mut starts: Vec<&str> = node_map
+        .keys()
+        .copied()
+        .filter(|s| s.ends_with('A'))
+        .collect();
+    starts.sort();
+
+    // Each candidate is a solution to the congruences so far, and the step it can't be before.
+    let mut candidates: Vec<(i128, i128, i128)> = vec![(0, 1, 0)];
+
+    for start in starts {
+        let ends = find_ends(instructions, &node_map, start)?;
+
+        candidates = candidates
+            .iter()
+            .flat_map(|&(x, m, earliest)| {
+                ends.iter().filter_map(move |&(step, period)| {
+                    crt(&[(x, m), (step, period)]).map(|(x, m)| (x, m, earliest.max(step)))
+                })
+            })
+            .collect();
+    }
+
+    candidates
+        .into_iter()
+        .map(|(x, m, earliest)| first_in_class(x, m, earliest))
+        .min()
+        .and_then(|step| usize::try_from(step).ok())
+        .ok_or_else(|| PuzzleError::solve("The ghosts never reach the end nodes together"))
+}
+
+// For a given instruction and starting node, follow the path until the same node comes up at the same
+// position through the instructions, at which point it is a cycle. Returns each step an end node is
+// reached on that cycle, along with the length of the cycle.
+fn find_ends(
+    instructions: &str,
+    node_map: &NodeMap,
+    start: &str,
+) -> PuzzleResult<Vec<(i128, i128)>> {
+    let mut seen: HashMap<(&str, usize), i128> = HashMap::new();
+    let mut ends = vec![];
+
+    let mut count = 0;
+    let mut current_node = start;
+
+    loop {
+        for (i, direction) in instructions.char_indices() {
+            // Parsing has already checked the directions and that every neighbor is a node.
+            let (left, right) = node_map[current_node];
+
+            current_node = if direction == 'L' { left } else { right };
+
+            count += 1;
+
+            if let Some(&cycle_start) = seen.get(&(current_node, i)) {
+                if ends.iter().any(|&end| end < cycle_start) {
+                    return Err(PuzzleError::solve(format!(
+                        "{start} reaches an end node before it starts cycling"
+                    )));
+                }
+
+                let period = count - cycle_start;
+
+                return Ok(ends.into_iter().map(|end| (end, period)).collect());
+            }
+
+            seen.insert((current_node, i), count);
+
+            if current_node.ends_with('Z') {
+                ends.push(count);
+            }
+        }
+    }
+}
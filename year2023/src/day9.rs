@@ -0,0 +1,93 @@
+use utilities::{parse_lines, parse_number, PuzzleError, PuzzleResult, Solution};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    fn day(&self) -> usize {
+        9
+    }
+
+    fn title(&self) -> &'static str {
+        "Mirage Maintenance"
+    }
+
+    fn part_1(&self, data: &str) -> PuzzleResult<String> {
+        part_1(data).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, data: &str) -> PuzzleResult<String> {
+        part_2(data).map(|answer| answer.to_string())
+    }
+}
+
+// The pattern seems like Pascal's triangle. It also seems like simple derivatives.
+fn part_1(data: &str) -> PuzzleResult<isize> {
+    Ok(parse_lines(data, process_line)?.iter().sum())
+}
+
+fn process_line(line: &str) -> PuzzleResult<isize> {
+    let mut line_numbers = convert_line(line)?;
+
+    let mut next_val = *line_numbers.iter().last().unwrap();
+
+    while !is_all_zero(&line_numbers) {
+        let new_line_numbers = calc_line_delta(&line_numbers);
+
+        next_val += new_line_numbers.iter().last().unwrap_or(&0);
+
+        line_numbers = new_line_numbers;
+    }
+
+    Ok(next_val)
+}
+
+type LineData = Vec<isize>;
+
+fn convert_line(line: &str) -> PuzzleResult<LineData> {
+    let line_numbers = line
+        .split_ascii_whitespace()
+        .map(parse_number)
+        .collect::<PuzzleResult<LineData>>()?;
+
+    if line_numbers.is_empty() {
+        return Err(PuzzleError::parse("Empty history"));
+    }
+
+    Ok(line_numbers)
+}
+
+fn is_all_zero(line: &LineData) -> bool {
+    line.iter().find(|val| **val != 0_isize).is_none()
+}
+
+fn calc_line_delta(line: &LineData) -> LineData {
+    line.iter()
+        .zip(line.iter().skip(1))
+        .map(|(a, b)| b - a)
+        .collect::<LineData>()
+}
+
+// This is the same, but in reverse, extrapolating the value before the first value.
+fn part_2(data: &str) -> PuzzleResult<isize> {
+    Ok(parse_lines(data, process_line_v2)?.iter().sum())
+}
+
+fn process_line_v2(line: &str) -> PuzzleResult<isize> {
+    let mut line_numbers = convert_line(line)?;
+
+    // Need to walk backwards in the list of first elements, so have to keep
+    // track of them all at first.
+    let mut first_vals = vec![];
+
+    first_vals.push(*line_numbers.first().unwrap());
+
+    while !is_all_zero(&line_numbers) {
+        let new_line_numbers = calc_line_delta(&line_numbers);
+
+        first_vals.push(*new_line_numbers.first().unwrap_or(&0));
+
+        line_numbers = new_line_numbers;
+    }
+
+    Ok(first_vals.iter().rfold(0, |acc, val| val - acc))
+}
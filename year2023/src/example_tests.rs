@@ -0,0 +1,10 @@
+use utilities::Part;
+
+use crate::registry;
+
+// The tests calling this are generated by build.rs.
+fn check_example(day: usize, example: usize, part: Part) {
+    utilities::check_example(&registry(), day, example, part);
+}
+
+include!(concat!(env!("OUT_DIR"), "/example_tests.rs"));
@@ -0,0 +1,63 @@
+#![allow(clippy::needless_range_loop)]
+
+use utilities::Registry;
+
+mod day1;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day17;
+mod day18;
+mod day19;
+mod day2;
+mod day20;
+mod day21;
+mod day22;
+mod day23;
+mod day24;
+mod day25;
+mod day3;
+mod day4;
+mod day5;
+mod day6;
+mod day7;
+mod day8;
+mod day9;
+#[cfg(test)]
+mod example_tests;
+
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+
+    registry.register(day1::Day1);
+    registry.register(day2::Day2);
+    registry.register(day3::Day3);
+    registry.register(day4::Day4);
+    registry.register(day5::Day5);
+    registry.register(day6::Day6);
+    registry.register(day7::Day7);
+    registry.register(day8::Day8);
+    registry.register(day9::Day9);
+    registry.register(day10::Day10);
+    registry.register(day11::Day11);
+    registry.register(day12::Day12);
+    registry.register(day13::Day13);
+    registry.register(day14::Day14);
+    registry.register(day15::Day15);
+    registry.register(day16::Day16);
+    registry.register(day17::Day17);
+    registry.register(day18::Day18);
+    registry.register(day19::Day19);
+    registry.register(day20::Day20);
+    registry.register(day21::Day21);
+    registry.register(day22::Day22);
+    registry.register(day23::Day23);
+    registry.register(day24::Day24);
+    registry.register(day25::Day25);
+
+    registry
+}